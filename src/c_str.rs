@@ -2,6 +2,12 @@
 
 // Provide safe abstraction (c! macro) for creating static C strings without runtime checks.
 // (c"..." requires Rust 1.77)
+//
+// Also provide CStrBuf and c_format! macro for building C strings at runtime without alloc.
+
+use core::{ffi::CStr, fmt};
+
+use crate::io;
 
 /// [`CStr`] literal macro.
 ///
@@ -80,6 +86,229 @@ pub const fn const_c_str_check(bytes: &[u8]) {
     }
 }
 
+/// A fixed-size buffer to build a [`CStr`] at runtime without `alloc`.
+///
+/// `N` is the size of the buffer, including the trailing nul.
+///
+/// [`Path`] is not available in `core`, so this crate uses [`CStr`] instead in the API where
+/// `std` uses [`Path`]. This type can be used where [`PathBuf`] or [`format!`] would be used
+/// with `std`, and can be passed to APIs that take `AsRef<CStr>` as is.
+///
+/// ```no_run
+/// use semihosting::{CStrBuf, c_format, fs};
+///
+/// let dir = "vectors";
+/// for n in 0..4_u8 {
+///     // with c_format! macro
+///     let path: CStrBuf<64> = c_format!("{dir}/case_{n}.bin")?;
+///     fs::write(&path, [n])?;
+///     // with push
+///     let mut path = CStrBuf::<64>::new();
+///     path.push(dir)?;
+///     path.push("case_0.bin")?;
+///     fs::remove_file(&path)?;
+/// }
+/// # Ok::<(), semihosting::io::Error>(())
+/// ```
+///
+/// # Errors
+///
+/// Methods that append to the buffer return an error of
+/// [`io::ErrorKind::InvalidFilename`] if the result does not fit in the buffer or
+/// the input contains an interior nul byte. In that case, the buffer is left unchanged.
+///
+/// [`Path`]: https://doc.rust-lang.org/std/path/struct.Path.html
+/// [`PathBuf`]: https://doc.rust-lang.org/std/path/struct.PathBuf.html
+/// [`format!`]: https://doc.rust-lang.org/std/macro.format.html
+#[derive(Clone)]
+pub struct CStrBuf<const N: usize> {
+    buf: [u8; N],
+    // Length of the string, excluding the trailing nul.
+    // If N is not zero, len < N and buf[len] is nul.
+    len: usize,
+}
+
+impl<const N: usize> CStrBuf<N> {
+    /// Creates an empty buffer.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { buf: [0; N], len: 0 }
+    }
+
+    /// Creates a buffer from formatted arguments.
+    ///
+    /// This is usually used via the [`c_format!`](crate::c_format!) macro.
+    pub fn from_fmt(args: fmt::Arguments<'_>) -> io::Result<Self> {
+        // Create a shim which translates a `fmt::Write` to an `io::Result` and saves
+        // off errors instead of discarding them.
+        struct Adapter<'a, const N: usize> {
+            inner: &'a mut CStrBuf<N>,
+            error: io::Result<()>,
+        }
+
+        impl<const N: usize> fmt::Write for Adapter<'_, N> {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                match self.inner.push_str(s) {
+                    Ok(()) => Ok(()),
+                    Err(e) => {
+                        self.error = Err(e);
+                        Err(fmt::Error)
+                    }
+                }
+            }
+        }
+
+        let mut buf = Self::new();
+        let mut output = Adapter { inner: &mut buf, error: Ok(()) };
+        match fmt::write(&mut output, args) {
+            Ok(()) => Ok(buf),
+            Err(..) => {
+                // check if the error came from the buffer or not
+                output.error?;
+                // This shouldn't happen: the buffer did not error, but somehow
+                // the formatter still errored?
+                panic!(
+                    "a formatting trait implementation returned an error when the underlying buffer did not"
+                );
+            }
+        }
+    }
+
+    /// Returns the contents of this buffer as a [`CStr`].
+    #[must_use]
+    pub fn as_c_str(&self) -> &CStr {
+        match self.buf.get(..=self.len) {
+            // SAFETY: buf[..len] contains no nul and buf[len] is nul.
+            Some(bytes) => unsafe { CStr::from_bytes_with_nul_unchecked(bytes) },
+            None => c!(""),
+        }
+    }
+
+    /// Returns the length of the string in this buffer, excluding the trailing nul.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the string in this buffer is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Truncates this buffer to the empty string.
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Appends the given string to the end of this buffer as is.
+    ///
+    /// # Errors
+    ///
+    /// See [the type-level documentation](Self#errors).
+    pub fn push_str<S: AsRef<[u8]>>(&mut self, s: S) -> io::Result<()> {
+        let s = s.as_ref();
+        if s.contains(&0) {
+            return Err(io::Error::INTERIOR_NUL_IN_PATH);
+        }
+        // Keep space for the trailing nul.
+        if s.len() >= N - self.len {
+            return Err(io::Error::PATH_TOO_LONG);
+        }
+        self.buf[self.len..self.len + s.len()].copy_from_slice(s);
+        self.truncate(self.len + s.len());
+        Ok(())
+    }
+
+    /// Extends this buffer with a path component, inserting a `/` separator if needed.
+    ///
+    /// This is similar to [`std::path::PathBuf::push`][std]:
+    ///
+    /// - if `component` is absolute (starts with `/`), it replaces the current contents.
+    /// - otherwise, `component` is appended with a `/` separator, unless this
+    ///   buffer is empty or already ends with `/`.
+    ///
+    /// [std]: https://doc.rust-lang.org/std/path/struct.PathBuf.html#method.push
+    ///
+    /// # Errors
+    ///
+    /// See [the type-level documentation](Self#errors).
+    pub fn push<S: AsRef<[u8]>>(&mut self, component: S) -> io::Result<()> {
+        let component = component.as_ref();
+        let prev_len = self.len;
+        if component.first() == Some(&b'/') {
+            self.len = 0;
+        } else if self.len != 0 && self.buf[self.len - 1] != b'/' {
+            self.push_str("/")?;
+        }
+        if let Err(e) = self.push_str(component) {
+            // Restore the original contents. (buf[..prev_len] has not been overwritten.)
+            self.truncate(prev_len);
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    fn truncate(&mut self, len: usize) {
+        if let Some(b) = self.buf.get_mut(len) {
+            *b = 0;
+            self.len = len;
+        }
+    }
+}
+
+impl<const N: usize> Default for CStrBuf<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> AsRef<CStr> for CStrBuf<N> {
+    fn as_ref(&self) -> &CStr {
+        self.as_c_str()
+    }
+}
+
+impl<const N: usize> fmt::Debug for CStrBuf<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_c_str(), f)
+    }
+}
+
+impl<const N: usize> fmt::Write for CStrBuf<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s).map_err(|_| fmt::Error)
+    }
+}
+
+/// Creates a [`CStrBuf`] using interpolation of runtime expressions.
+///
+/// This takes the same arguments as [`format!`], and returns `io::Result<CStrBuf<N>>`.
+/// The size of the buffer `N` is usually inferred from the context.
+///
+/// ```no_run
+/// use semihosting::{CStrBuf, c_format, fs};
+///
+/// let n = 1;
+/// let path: CStrBuf<64> = c_format!("vectors/case_{n}.bin")?;
+/// let _input = fs::File::open(path)?;
+/// # Ok::<(), semihosting::io::Error>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns an error of [`io::ErrorKind::InvalidFilename`] if the result does not
+/// fit in the buffer or contains an interior nul byte.
+///
+/// [`format!`]: https://doc.rust-lang.org/std/macro.format.html
+/// [`io::ErrorKind::InvalidFilename`]: crate::io::ErrorKind::InvalidFilename
+#[macro_export]
+macro_rules! c_format {
+    ($($tt:tt)*) => {
+        $crate::CStrBuf::from_fmt($crate::__private::format_args!($($tt)*))
+    };
+}
+
 #[allow(
     clippy::alloc_instead_of_core,
     clippy::std_instead_of_alloc,
@@ -89,7 +318,10 @@ pub const fn const_c_str_check(bytes: &[u8]) {
 )]
 #[cfg(test)]
 mod tests {
-    use core::ffi::CStr;
+    use core::{ffi::CStr, fmt::Write as _};
+
+    use super::CStrBuf;
+    use crate::io;
 
     #[test]
     fn test_c_macro() {
@@ -123,4 +355,77 @@ mod tests {
         t(b"ab\0c\0");
         t(b"\0\0");
     }
+
+    #[test]
+    fn test_c_str_buf() {
+        #[track_caller]
+        fn t<const N: usize>(s: &CStrBuf<N>, raw: &[u8]) {
+            assert_eq!(s.as_c_str().to_bytes_with_nul(), raw);
+            assert_eq!(s.len(), raw.len() - 1);
+        }
+        #[track_caller]
+        fn e(r: io::Result<()>) {
+            assert_eq!(r.unwrap_err().kind(), io::ErrorKind::InvalidFilename);
+        }
+
+        let mut s = CStrBuf::<8>::new();
+        t(&s, b"\0");
+        assert!(s.is_empty());
+        s.push("a").unwrap();
+        t(&s, b"a\0");
+        s.push("b").unwrap();
+        t(&s, b"a/b\0");
+        s.push_str("c").unwrap();
+        t(&s, b"a/bc\0");
+        s.push_str("/").unwrap();
+        s.push("d").unwrap();
+        t(&s, b"a/bc/d\0");
+        // overflow
+        e(s.push("e"));
+        t(&s, b"a/bc/d\0");
+        e(s.push_str("ef"));
+        t(&s, b"a/bc/d\0");
+        s.push_str("e").unwrap();
+        t(&s, b"a/bc/de\0");
+        e(s.push_str("f"));
+        e(s.push("/abcdefgh"));
+        t(&s, b"a/bc/de\0");
+        // interior nul
+        e(s.push_str("\0"));
+        s.clear();
+        e(s.push("a\0b"));
+        t(&s, b"\0");
+        // absolute
+        s.push("a").unwrap();
+        s.push("/b").unwrap();
+        t(&s, b"/b\0");
+        s.push("c/").unwrap();
+        s.push("d").unwrap();
+        t(&s, b"/b/c/d\0");
+        assert_eq!(std::format!("{s:?}"), "\"/b/c/d\"");
+        s.clear();
+        let n = 1;
+        write!(s, "x/{n}").unwrap();
+        t(&s, b"x/1\0");
+
+        let mut s = CStrBuf::<0>::new();
+        t(&s, b"\0");
+        e(s.push_str(""));
+        let mut s = CStrBuf::<1>::new();
+        s.push_str("").unwrap();
+        e(s.push_str("a"));
+        t(&s, b"\0");
+    }
+
+    #[test]
+    fn test_c_format() {
+        let dir = "dir";
+        let n = 10;
+        let s: CStrBuf<16> = c_format!("{dir}/case_{n}.bin").unwrap();
+        assert_eq!(s.as_c_str(), c!("dir/case_10.bin"));
+        let r: io::Result<CStrBuf<15>> = c_format!("{dir}/case_{n}.bin");
+        assert_eq!(r.unwrap_err().kind(), io::ErrorKind::InvalidFilename);
+        let r: io::Result<CStrBuf<16>> = c_format!("a{}b", '\0');
+        assert_eq!(r.unwrap_err().kind(), io::ErrorKind::InvalidFilename);
+    }
 }
@@ -0,0 +1,189 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! [defmt] global logger backed by semihosting.
+//!
+//! When the `defmt` feature is enabled, this crate provides a
+//! `#[defmt::global_logger]` that writes encoded defmt frames to the standard
+//! output of the host, or to the host file specified by [`set_output`].
+//! The output can be decoded on the host by [`defmt-print`] or other tools
+//! based on `defmt-decoder`:
+//!
+//! ```sh
+//! qemu-system-arm <args> | defmt-print -e <path/to/binary>
+//! # or, when `set_output(c"defmt.bin")` has been called:
+//! defmt-print -e <path/to/binary> < defmt.bin
+//! ```
+//!
+//! Each frame is accumulated in a fixed-size buffer and written to the host with
+//! as few semihosting calls as possible (usually one per frame).
+//!
+//! The logger uses a [critical section][critical-section] to protect the
+//! logger state, so your application needs to provide a `critical-section`
+//! implementation (e.g., the `critical-section-single-core` feature of `cortex-m`).
+//!
+//! As with other defmt global loggers, you need to pass `-C link-arg=-Tdefmt.x`
+//! to the linker.
+//!
+//! defmt frames are binary data, but the standard output of the host may be in
+//! text mode (e.g., on Windows hosts, `\n` bytes may be converted to `\r\n`),
+//! which corrupts the frames. Use [`set_output`] to write frames to a host file
+//! in such environments.
+//!
+//! [defmt]: https://github.com/knurling-rs/defmt
+//! [`defmt-print`]: https://crates.io/crates/defmt-print
+//! [critical-section]: https://github.com/rust-embedded/critical-section
+
+use core::{
+    cell::UnsafeCell,
+    ffi::CStr,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use ::critical_section::RestoreState;
+use ::defmt::Encoder;
+
+use crate::{
+    fs,
+    io::{self, Write as _},
+    utils::ChunkBuf,
+};
+
+/// The size of the buffer used to accumulate a frame.
+///
+/// Frames larger than this are written with multiple semihosting calls.
+const BUF_SIZE: usize = 256;
+
+/// Directs subsequent defmt frames to the specified host file.
+///
+/// The file is created if it does not exist, and truncated if it does.
+///
+/// By default (i.e., if this function has not been called), defmt frames are
+/// written to the standard output. The file is opened in binary mode, so this
+/// is needed on hosts whose standard output is in text mode (e.g., Windows).
+///
+/// # Platform-specific behavior
+///
+/// See [`fs::File::create`] for the semihosting calls used to open the file.
+pub fn set_output<P: AsRef<CStr>>(path: P) -> io::Result<()> {
+    let file = fs::File::create(path)?;
+    let prev = ::critical_section::with(|_| {
+        // SAFETY: we are in a critical section, and the logger state is only
+        // accessed in a critical section.
+        let state = unsafe { &mut *STATE.0.get() };
+        // Do not replace the output in the middle of a frame.
+        assert!(!TAKEN.load(Ordering::Relaxed), "defmt logger is in use");
+        state.buf.flush();
+        core::mem::replace(&mut state.buf.output, Output::File(file))
+    });
+    // Close the previous file (if any) outside of the critical section.
+    drop(prev);
+    Ok(())
+}
+
+#[::defmt::global_logger]
+struct Logger;
+
+static TAKEN: AtomicBool = AtomicBool::new(false);
+static STATE: StateCell = StateCell(UnsafeCell::new(State {
+    restore: RestoreState::invalid(),
+    encoder: Encoder::new(),
+    buf: FrameBuf { buf: ChunkBuf::new(), output: Output::Uninit },
+}));
+
+struct StateCell(UnsafeCell<State>);
+// SAFETY: the state is only accessed in a critical section: either between
+// `Logger::acquire` and `Logger::release`, or in `set_output`.
+unsafe impl Sync for StateCell {}
+
+struct State {
+    restore: RestoreState,
+    encoder: Encoder,
+    buf: FrameBuf,
+}
+
+struct FrameBuf {
+    buf: ChunkBuf<BUF_SIZE>,
+    output: Output,
+}
+
+impl FrameBuf {
+    fn write(&mut self, bytes: &[u8]) {
+        let Self { buf, output } = self;
+        buf.write(bytes, |b| output.write_all(b));
+    }
+    fn flush(&mut self) {
+        let Self { buf, output } = self;
+        buf.flush(|b| output.write_all(b));
+    }
+}
+
+enum Output {
+    /// Standard output has not been opened yet.
+    Uninit,
+    /// Failed to open the standard output.
+    Unavailable,
+    Stdout(io::Stdout),
+    File(fs::File),
+}
+
+impl Output {
+    fn write_all(&mut self, bytes: &[u8]) {
+        if let Self::Uninit = self {
+            *self = match io::stdout() {
+                Ok(stdout) => Self::Stdout(stdout),
+                Err(_) => Self::Unavailable,
+            };
+        }
+        // `defmt::Logger` has no way to report errors, so frames that could
+        // not be written are dropped.
+        let _ = match self {
+            Self::Stdout(stdout) => stdout.write_all(bytes),
+            Self::File(file) => file.write_all(bytes),
+            Self::Uninit | Self::Unavailable => Ok(()),
+        };
+    }
+}
+
+// SAFETY: `acquire` enters a critical section and `release` exits it, and
+// nested `acquire` is rejected, so `write`/`flush`/`release` have exclusive
+// access to the logger state.
+unsafe impl ::defmt::Logger for Logger {
+    fn acquire() {
+        // SAFETY: the critical section is released in `release`.
+        let restore = unsafe { ::critical_section::acquire() };
+        if TAKEN.load(Ordering::Relaxed) {
+            panic!("defmt logger taken reentrantly");
+        }
+        TAKEN.store(true, Ordering::Relaxed);
+        // SAFETY: we are in a critical section and the logger is not taken by others.
+        let state = unsafe { &mut *STATE.0.get() };
+        state.restore = restore;
+        let State { encoder, buf, .. } = state;
+        encoder.start_frame(|b| buf.write(b));
+    }
+
+    unsafe fn flush() {
+        // SAFETY: the caller must call this between `acquire` and `release`.
+        let state = unsafe { &mut *STATE.0.get() };
+        state.buf.flush();
+    }
+
+    unsafe fn release() {
+        // SAFETY: the caller must call this after `acquire`.
+        let state = unsafe { &mut *STATE.0.get() };
+        let State { encoder, buf, .. } = state;
+        encoder.end_frame(|b| buf.write(b));
+        buf.flush();
+        TAKEN.store(false, Ordering::Relaxed);
+        let restore = state.restore;
+        // SAFETY: the critical section was acquired in `acquire`.
+        unsafe { ::critical_section::release(restore) }
+    }
+
+    unsafe fn write(bytes: &[u8]) {
+        // SAFETY: the caller must call this between `acquire` and `release`.
+        let state = unsafe { &mut *STATE.0.get() };
+        let State { encoder, buf, .. } = state;
+        encoder.write(bytes, |b| buf.write(b));
+    }
+}
@@ -218,6 +218,11 @@ impl SystemTime {
     /// [std]: https://doc.rust-lang.org/std/time/struct.SystemTime.html#associatedconstant.UNIX_EPOCH
     pub const UNIX_EPOCH: Self = Self(time::UNIX_EPOCH);
 
+    #[cfg(feature = "fs")]
+    pub(crate) fn from_inner(t: time::SystemTime) -> Self {
+        Self(t)
+    }
+
     // TODO(time): return result?
     /// Returns the system time corresponding to "now".
     #[must_use]
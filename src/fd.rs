@@ -204,20 +204,3 @@ macro_rules! impl_as_fd {
         }
     )*};
 }
-#[cfg(feature = "fs")]
-macro_rules! impl_from_fd {
-    ($($ty:ty),* $(,)?) => {$(
-        impl From<$ty> for OwnedFd {
-            #[inline]
-            fn from(this: $ty) -> Self {
-                this.0
-            }
-        }
-        impl From<OwnedFd> for $ty {
-            #[inline]
-            fn from(owned_fd: OwnedFd) -> Self {
-                Self(owned_fd)
-            }
-        }
-    )*};
-}
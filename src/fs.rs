@@ -29,14 +29,127 @@
 //! [`std::fs`]: https://doc.rust-lang.org/std/fs/index.html
 //! [`Path`]: https://doc.rust-lang.org/std/path/struct.Path.html
 
-use core::{ffi::CStr, fmt};
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+use core::{
+    ffi::CStr,
+    fmt,
+    mem::{ManuallyDrop, MaybeUninit},
+    sync::atomic::{AtomicU8, Ordering},
+};
 
+#[cfg(feature = "time")]
+use crate::experimental::time::SystemTime;
 use crate::{
     fd::{AsFd as _, OwnedFd},
     io::{self, Write as _},
     sys,
+    utils::slice_assume_init_mut,
 };
 
+/// Reads the entire contents of a file into a bytes vector.
+///
+/// See [`std::fs::read` documentation][std] for details.
+///
+/// See also [`read_into`] which does not require `alloc`.
+///
+/// [std]: https://doc.rust-lang.org/std/fs/fn.read.html
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn read<P: AsRef<CStr>>(path: P) -> io::Result<Vec<u8>> {
+    fn inner(path: &CStr) -> io::Result<Vec<u8>> {
+        let mut file = File::open(path)?;
+        let size = buffer_capacity_required(&file);
+        let mut bytes = Vec::new();
+        bytes.try_reserve_exact(size.unwrap_or(0))?;
+        io::default_read_to_end(&mut file, &mut bytes, size)?;
+        Ok(bytes)
+    }
+    inner(path.as_ref())
+}
+
+/// Reads the entire contents of a file into a string.
+///
+/// See [`std::fs::read_to_string` documentation][std] for details.
+///
+/// [std]: https://doc.rust-lang.org/std/fs/fn.read_to_string.html
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn read_to_string<P: AsRef<CStr>>(path: P) -> io::Result<String> {
+    fn inner(path: &CStr) -> io::Result<String> {
+        let mut file = File::open(path)?;
+        let size = buffer_capacity_required(&file);
+        let mut string = String::new();
+        string.try_reserve_exact(size.unwrap_or(0))?;
+        io::default_read_to_string(&mut file, &mut string, size)?;
+        Ok(string)
+    }
+    inner(path.as_ref())
+}
+
+/// Reads the entire contents of a file into the provided buffer, and returns
+/// the part of the buffer that was filled.
+///
+/// This is a variant of [`read`] that does not require `alloc`.
+///
+/// See also [`read_into_uninit`] that takes potentially uninitialized buffer.
+///
+/// # Errors
+///
+/// In addition to the errors of [`File::open`] and [`Read::read`](io::Read::read),
+/// this function will return an error of kind [`ErrorKind::FileTooLarge`](io::ErrorKind::FileTooLarge)
+/// if the file is larger than `buf`.
+pub fn read_into<P: AsRef<CStr>>(path: P, buf: &mut [u8]) -> io::Result<&[u8]> {
+    let len = buf.len();
+    // SAFETY: transmuting initialized `&mut [u8]` to `&mut [MaybeUninit<u8>]` is safe unless uninitialized byte will be written to resulting slice.
+    let buf =
+        unsafe { core::slice::from_raw_parts_mut(buf.as_mut_ptr().cast::<MaybeUninit<u8>>(), len) };
+    read_into_uninit(path, buf).map(|buf| &*buf)
+}
+
+/// Reads the entire contents of a file into the provided buffer, and returns
+/// the part of the buffer that was filled.
+///
+/// Unlike [`read_into`], this takes potentially uninitialized buffer.
+///
+/// See [`read_into`] for details.
+pub fn read_into_uninit<P: AsRef<CStr>>(
+    path: P,
+    buf: &mut [MaybeUninit<u8>],
+) -> io::Result<&mut [u8]> {
+    fn inner<'a>(path: &CStr, buf: &'a mut [MaybeUninit<u8>]) -> io::Result<&'a mut [u8]> {
+        let file = File::open(path)?;
+        // Fail early if we know that the file doesn't fit in the buffer.
+        if let Ok(m) = file.metadata() {
+            if m.len() > buf.len() as u64 {
+                return Err(io::Error::FILE_LARGER_THAN_BUFFER);
+            }
+        }
+        let mut filled = 0;
+        loop {
+            if filled == buf.len() {
+                // The buffer might be an exact fit. Check if we've reached EOF.
+                let mut probe = [0_u8; 1];
+                match sys::read(file.as_fd(), &mut probe) {
+                    Ok(0) => break,
+                    Ok(_) => return Err(io::Error::FILE_LARGER_THAN_BUFFER),
+                    Err(ref e) if e.is_interrupted() => continue,
+                    Err(e) => return Err(e),
+                }
+            }
+            match sys::read_uninit(file.as_fd(), &mut buf[filled..]) {
+                Ok((&mut [], _)) => break,
+                Ok((read, _)) => filled += read.len(),
+                Err(ref e) if e.is_interrupted() => {}
+                Err(e) => return Err(e),
+            }
+        }
+        // SAFETY: we've filled the first `filled` bytes.
+        Ok(unsafe { slice_assume_init_mut(&mut buf[..filled]) })
+    }
+    inner(path.as_ref(), buf)
+}
+
 /// Write a slice as the entire contents of a file.
 ///
 /// See [`std::fs::write` documentation][std] for details.
@@ -49,6 +162,48 @@ pub fn write<P: AsRef<CStr>, C: AsRef<[u8]>>(path: P, contents: C) -> io::Result
     inner(path.as_ref(), contents.as_ref())
 }
 
+/// Copies the contents of one file to another.
+///
+/// This function will overwrite the contents of `to`.
+///
+/// On success, the total number of bytes copied is returned.
+///
+/// See [`std::fs::copy` documentation][std] for details.
+///
+/// [std]: https://doc.rust-lang.org/std/fs/fn.copy.html
+///
+/// # Platform-specific behavior
+///
+/// Data is copied through a stack buffer, which is currently 4 KiB; this is
+/// larger than the buffer used by [`io::copy`] to reduce the number of
+/// semihosting calls. The first chunk is read from `from` before `to` is
+/// created or truncated, so if `from` cannot be read (e.g., it is a directory),
+/// `to` is left untouched.
+///
+/// On MIPS32 and MIPS64, the permission bits of `from` are passed to `UHI_open`
+/// when `to` is created. UHI has no way to change the permissions of an existing
+/// file, so the permissions of `to` are not changed if it already exists.
+/// Arm semihosting has no way to query or set the permissions, so the
+/// permissions are not copied on AArch64, Arm, RISC-V, LoongArch, and Xtensa.
+///
+/// The following semihosting calls are currently being used:
+///
+/// | Platform                                                      | Semihosting call                                    |
+/// | ------------------------------------------------------------- | --------------------------------------------------- |
+/// | AArch64, Arm, RISC-V, LoongArch, Xtensa (openocd-semihosting) | [SYS_OPEN], [SYS_READ], [SYS_WRITE], [SYS_CLOSE]    |
+/// | MIPS32, MIPS64                                                | UHI_open, UHI_fstat, UHI_read, UHI_write, UHI_close |
+///
+/// [SYS_OPEN]: https://github.com/ARM-software/abi-aa/blob/2025Q4/semihosting/semihosting.rst#sys-open-0x01
+/// [SYS_READ]: https://github.com/ARM-software/abi-aa/blob/2025Q4/semihosting/semihosting.rst#sys-read-0x06
+/// [SYS_WRITE]: https://github.com/ARM-software/abi-aa/blob/2025Q4/semihosting/semihosting.rst#sys-write-0x05
+/// [SYS_CLOSE]: https://github.com/ARM-software/abi-aa/blob/2025Q4/semihosting/semihosting.rst#sys-close-0x02
+///
+/// **Disclaimer:** These semihosting calls might change over time.
+#[doc(alias = "cp", alias = "CopyFile", alias = "CopyFileEx")]
+pub fn copy<P: AsRef<CStr>, Q: AsRef<CStr>>(from: P, to: Q) -> io::Result<u64> {
+    sys::fs::copy(from.as_ref(), to.as_ref())
+}
+
 /// Removes a file from the host filesystem.
 ///
 /// See [`std::fs::remove_file` documentation][std] for details.
@@ -85,26 +240,225 @@ pub fn remove_file<P: AsRef<CStr>>(path: P) -> io::Result<()> {
 ///
 /// The following semihosting calls are currently being used:
 ///
-/// | Platform                                                      | Semihosting call |
-/// | ------------------------------------------------------------- | ---------------- |
-/// | AArch64, Arm, RISC-V, LoongArch, Xtensa (openocd-semihosting) | [SYS_RENAME]     |
-/// | MIPS32, MIPS64                                                | (Unsupported)    |
+/// | Platform                                                      | Semihosting call     |
+/// | ------------------------------------------------------------- | -------------------- |
+/// | AArch64, Arm, RISC-V, LoongArch, Xtensa (openocd-semihosting) | [SYS_RENAME]         |
+/// | MIPS32, MIPS64                                                | UHI_link, UHI_unlink |
 ///
 /// [SYS_RENAME]: https://github.com/ARM-software/abi-aa/blob/2025Q4/semihosting/semihosting.rst#sys-rename-0x0f
 ///
 /// **Disclaimer:** These semihosting calls might change over time.
+///
+/// MIPS UHI doesn't provide rename, so on MIPS32 and MIPS64 this is emulated by
+/// creating a hard link to `from` at `to` and then removing `from`. Unlike on
+/// other platforms:
+///
+/// - If `to` already exists, this fails with [`io::ErrorKind::AlreadyExists`]
+///   instead of replacing it, so that no file is lost if one of the
+///   semihosting calls fails.
+/// - This is not atomic: between the two semihosting calls, the file is
+///   accessible under both names. If removing `from` fails, the link at `to`
+///   is removed again.
+/// - This doesn't work for directories.
+///
+/// Note that QEMU (as of 10.2) doesn't support UHI_link and aborts the emulation
+/// on unsupported UHI operations, so on QEMU this terminates the program instead
+/// of returning an error.
 #[doc(alias = "mv", alias = "MoveFile", alias = "MoveFileEx")]
 #[doc(alias = "SYS_RENAME")] // arm_compat
 pub fn rename<P: AsRef<CStr>, Q: AsRef<CStr>>(from: P, to: Q) -> io::Result<()> {
     sys::fs::rename(from.as_ref(), to.as_ref())
 }
 
+/// Creates a new hard link on the host filesystem.
+///
+/// The `link` path will be a link pointing to the `original` path. Note that
+/// systems often require these two paths to both be located on the same
+/// filesystem.
+///
+/// See [`std::fs::hard_link` documentation][std] for details.
+///
+/// [std]: https://doc.rust-lang.org/std/fs/fn.hard_link.html
+///
+/// # Platform-specific behavior
+///
+/// The following semihosting calls are currently being used:
+///
+/// | Platform                                                      | Semihosting call |
+/// | ------------------------------------------------------------- | ---------------- |
+/// | AArch64, Arm, RISC-V, LoongArch, Xtensa (openocd-semihosting) | (Unsupported)    |
+/// | MIPS32, MIPS64                                                | UHI_link         |
+///
+/// **Disclaimer:** These semihosting calls might change over time.
+///
+/// Note that QEMU (as of 10.2) doesn't support UHI_link and aborts the emulation
+/// on unsupported UHI operations, so on QEMU this terminates the program instead
+/// of returning an error.
+#[doc(alias = "CreateHardLink", alias = "linkat")]
+#[doc(alias = "UHI_link")] // mips
+pub fn hard_link<P: AsRef<CStr>, Q: AsRef<CStr>>(original: P, link: Q) -> io::Result<()> {
+    sys::fs::hard_link(original.as_ref(), link.as_ref())
+}
+
+/// Given a path, queries the host filesystem to get information about a file.
+///
+/// See [`std::fs::metadata` documentation][std] for details.
+///
+/// [std]: https://doc.rust-lang.org/std/fs/fn.metadata.html
+///
+/// # Platform-specific behavior
+///
+/// Semihosting has no way to query the metadata of a file by path, so this
+/// function opens the file in read-only mode, queries the metadata of the
+/// opened file, and closes it. Therefore, this function fails if the file
+/// exists but cannot be opened for reading.
+///
+/// On AArch64, Arm, RISC-V, LoongArch, and Xtensa, the file type of the returned
+/// metadata is unknown, so this cannot tell whether `path` is a file or a
+/// directory (see [`Metadata`] for details).
+///
+/// The following semihosting calls are currently being used:
+///
+/// | Platform                                                      | Semihosting call                    |
+/// | ------------------------------------------------------------- | ----------------------------------- |
+/// | AArch64, Arm, RISC-V, LoongArch, Xtensa (openocd-semihosting) | [SYS_OPEN], [SYS_FLEN], [SYS_CLOSE] |
+/// | MIPS32, MIPS64                                                | UHI_open, UHI_fstat, UHI_close      |
+///
+/// [SYS_OPEN]: https://github.com/ARM-software/abi-aa/blob/2025Q4/semihosting/semihosting.rst#sys-open-0x01
+/// [SYS_FLEN]: https://github.com/ARM-software/abi-aa/blob/2025Q4/semihosting/semihosting.rst#sys-flen-0x0c
+/// [SYS_CLOSE]: https://github.com/ARM-software/abi-aa/blob/2025Q4/semihosting/semihosting.rst#sys-close-0x02
+///
+/// **Disclaimer:** These semihosting calls might change over time.
+#[doc(alias = "stat")]
+pub fn metadata<P: AsRef<CStr>>(path: P) -> io::Result<Metadata> {
+    File::open(path)?.metadata()
+}
+
+/// Queries the metadata about a file without following symlinks.
+///
+/// See [`std::fs::symlink_metadata` documentation][std] for details.
+///
+/// [std]: https://doc.rust-lang.org/std/fs/fn.symlink_metadata.html
+///
+/// # Platform-specific behavior
+///
+/// Semihosting has no way to query the metadata of a symbolic link itself, so
+/// this function currently follows symbolic links and is equivalent to [`metadata`].
+#[doc(alias = "lstat")]
+pub fn symlink_metadata<P: AsRef<CStr>>(path: P) -> io::Result<Metadata> {
+    metadata(path)
+}
+
+/// Returns `Ok(true)` if the path points at an existing entity.
+///
+/// See [`std::fs::exists` documentation][std] for details.
+///
+/// This function returns `Ok(false)` only if the host reports that the file
+/// does not exist (i.e., an error of [`io::ErrorKind::NotFound`]), and returns
+/// other errors (e.g., permission denied) as is. This can be used to check for
+/// optional input files on the host.
+///
+/// [std]: https://doc.rust-lang.org/std/fs/fn.exists.html
+///
+/// # Platform-specific behavior
+///
+/// This function uses [`metadata`], so see its documentation for the semihosting calls used.
+#[doc(alias = "try_exists")]
+pub fn exists<P: AsRef<CStr>>(path: P) -> io::Result<bool> {
+    match metadata(path) {
+        Ok(_) => Ok(true),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+/// Returns an iterator over the entries within a directory.
+///
+/// The iterator will yield instances of <code>[io::Result]<[DirEntry]></code>.
+/// New errors may be encountered after an iterator is initially constructed.
+/// Entries for the current and parent directories (typically `.` and `..`) are skipped.
+///
+/// See [`std::fs::read_dir` documentation][std] for details.
+///
+/// [std]: https://doc.rust-lang.org/std/fs/fn.read_dir.html
+///
+/// # Platform-specific behavior
+///
+/// The following semihosting calls are currently being used:
+///
+/// | Platform                                                      | Semihosting call                           |
+/// | ------------------------------------------------------------- | ------------------------------------------ |
+/// | AArch64, Arm, RISC-V, LoongArch, Xtensa (openocd-semihosting) | (Unsupported)                              |
+/// | MIPS32, MIPS64                                                | UHI_findfirst, UHI_findnext, UHI_findclose |
+///
+/// **Disclaimer:** These semihosting calls might change over time.
+///
+/// Note that QEMU (as of 10.2) doesn't support UHI_findfirst.
+#[doc(alias = "ls", alias = "opendir", alias = "readdir")]
+#[doc(alias = "UHI_findfirst", alias = "UHI_findnext")] // mips
+pub fn read_dir<P: AsRef<CStr>>(path: P) -> io::Result<ReadDir> {
+    sys::fs::read_dir(path.as_ref()).map(ReadDir)
+}
+
+/// Iterator over the entries in a directory.
+///
+/// This iterator is returned from the [`read_dir`] function of this module and
+/// will yield instances of <code>[io::Result]<[DirEntry]></code>.
+///
+/// The directory is closed when this is dropped.
+///
+/// See [`std::fs::ReadDir` documentation][std] for details.
+///
+/// [std]: https://doc.rust-lang.org/std/fs/struct.ReadDir.html
+pub struct ReadDir(sys::fs::ReadDir);
+
+impl Iterator for ReadDir {
+    type Item = io::Result<DirEntry>;
+    fn next(&mut self) -> Option<io::Result<DirEntry>> {
+        self.0.next().map(|entry| entry.map(DirEntry))
+    }
+}
+
+impl fmt::Debug for ReadDir {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReadDir").finish_non_exhaustive()
+    }
+}
+
+/// Entries returned by the [`ReadDir`] iterator.
+///
+/// The name of the entry is stored in a fixed-size buffer in this struct, so
+/// this does not require `alloc`.
+///
+/// See [`std::fs::DirEntry` documentation][std] for details.
+///
+/// [std]: https://doc.rust-lang.org/std/fs/struct.DirEntry.html
+pub struct DirEntry(sys::fs::DirEntry);
+
+impl DirEntry {
+    /// Returns the file name of this directory entry without any leading path component(s).
+    ///
+    /// See [`std::fs::DirEntry::file_name` documentation][std] for details.
+    ///
+    /// [std]: https://doc.rust-lang.org/std/fs/struct.DirEntry.html#method.file_name
+    #[must_use]
+    pub fn file_name(&self) -> &CStr {
+        self.0.file_name()
+    }
+}
+
+impl fmt::Debug for DirEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("DirEntry").field(&self.file_name()).finish()
+    }
+}
+
 /// An object providing access to an open file on the host filesystem.
 ///
 /// See [`std::fs::File` documentation][std] for details.
 ///
 /// [std]: https://doc.rust-lang.org/std/fs/struct.File.html
-pub struct File(OwnedFd);
+pub struct File(sys::fs::File);
 
 impl File {
     /// Attempts to open a file in read-only mode.
@@ -142,20 +496,44 @@ impl File {
 }
 
 impl_as_fd!(File);
-impl_from_fd!(File);
+impl crate::sealed::Sealed for File {}
+impl From<File> for OwnedFd {
+    #[inline]
+    fn from(file: File) -> Self {
+        file.0.into_fd()
+    }
+}
+impl From<OwnedFd> for File {
+    #[inline]
+    fn from(owned_fd: OwnedFd) -> Self {
+        Self(sys::fs::File::from_fd(owned_fd))
+    }
+}
 impl fmt::Debug for File {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("File").field("fd", &self.as_fd().as_raw_fd()).finish()
     }
 }
 impl io::Read for File {
+    #[inline]
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
-        sys::read(self.as_fd(), buf)
+        (&*self).read(buf)
+    }
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
+        (&*self).read_to_end(buf)
+    }
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_to_string(&mut self, buf: &mut String) -> io::Result<usize> {
+        (&*self).read_to_string(buf)
     }
 }
 impl io::Write for File {
+    #[inline]
     fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
-        sys::write(self.as_fd(), bytes)
+        (&*self).write(bytes)
     }
     #[inline]
     fn flush(&mut self) -> io::Result<()> {
@@ -163,18 +541,51 @@ impl io::Write for File {
     }
 }
 impl io::Seek for File {
+    #[inline]
     fn seek(&mut self, pos: io::SeekFrom) -> io::Result<u64> {
-        sys::fs::seek(self.as_fd(), pos)
+        (&*self).seek(pos)
+    }
+    #[inline]
+    fn stream_position(&mut self) -> io::Result<u64> {
+        (&*self).stream_position()
     }
 }
 impl io::Read for &File {
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
-        sys::read(self.as_fd(), buf)
+        self.0.read(buf)
     }
+    /// Reads all bytes until EOF in this source, placing them into `buf`.
+    ///
+    /// Unlike the default implementation, this reserves the buffer up front
+    /// based on the file size, to reduce the number of semihosting calls.
+    #[cfg(feature = "alloc")]
+    fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
+        let size = buffer_capacity_required(self);
+        buf.try_reserve(size.unwrap_or(0))?;
+        io::default_read_to_end(self, buf, size)
+    }
+    /// Reads all bytes until EOF in this source, appending them to `buf`.
+    ///
+    /// Unlike the default implementation, this reserves the buffer up front
+    /// based on the file size, to reduce the number of semihosting calls.
+    #[cfg(feature = "alloc")]
+    fn read_to_string(&mut self, buf: &mut String) -> io::Result<usize> {
+        let size = buffer_capacity_required(self);
+        buf.try_reserve(size.unwrap_or(0))?;
+        io::default_read_to_string(self, buf, size)
+    }
+}
+/// Returns the number of bytes that `read_to_end` is expected to read.
+#[cfg(feature = "alloc")]
+fn buffer_capacity_required(mut file: &File) -> Option<usize> {
+    let size = file.metadata().map(|m| m.len()).ok()?;
+    let pos = io::Seek::stream_position(&mut file).ok()?;
+    // Don't worry about `usize` overflow because reading will fail regardless in that case.
+    Some(usize::try_from(size.saturating_sub(pos)).unwrap_or(usize::MAX))
 }
 impl io::Write for &File {
     fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
-        sys::write(self.as_fd(), bytes)
+        self.0.write(bytes)
     }
     #[inline]
     fn flush(&mut self) -> io::Result<()> {
@@ -183,7 +594,114 @@ impl io::Write for &File {
 }
 impl io::Seek for &File {
     fn seek(&mut self, pos: io::SeekFrom) -> io::Result<u64> {
-        sys::fs::seek(self.as_fd(), pos)
+        self.0.seek(pos)
+    }
+    /// Returns the current seek position from the start of the stream.
+    ///
+    /// On platforms where semihosting doesn't provide a way to query the
+    /// current position, this returns the position tracked by `File` without
+    /// issuing a semihosting call.
+    fn stream_position(&mut self) -> io::Result<u64> {
+        self.0.stream_position()
+    }
+}
+
+/// Extension trait for [`File`] to provide positional read and write.
+///
+/// This is similar to [`std::os::unix::fs::FileExt`][std], but is available on
+/// all platforms supported by this crate.
+///
+/// [std]: https://doc.rust-lang.org/std/os/unix/fs/trait.FileExt.html
+///
+/// # Platform-specific behavior
+///
+/// See [`sys::pread`] and [`sys::pwrite`] for the semihosting calls used.
+///
+/// On AArch64, Arm, RISC-V, LoongArch, and Xtensa, Arm semihosting has no
+/// positional read/write, so these methods seek to the given offset, read/write,
+/// and then seek back to the previous position. As with reads/writes via `&File`,
+/// concurrent calls on the same file race on the position.
+///
+/// On MIPS32 and MIPS64, these methods use `UHI_pread`/`UHI_pwrite`. Note that
+/// this path is not verified by the tests of this crate, because QEMU (as of 10.2)
+/// doesn't support these operations.
+pub trait FileExt: crate::sealed::Sealed {
+    /// Reads a number of bytes starting from a given offset.
+    ///
+    /// Returns the number of bytes read.
+    ///
+    /// The offset is relative to the start of the file and thus independent
+    /// from the current cursor. The current file cursor is not affected by
+    /// this function.
+    ///
+    /// See [`std::os::unix::fs::FileExt::read_at` documentation][std] for details.
+    ///
+    /// [std]: https://doc.rust-lang.org/std/os/unix/fs/trait.FileExt.html#tymethod.read_at
+    #[doc(alias = "pread")]
+    fn read_at(&self, buf: &mut [u8], offset: u64) -> io::Result<usize>;
+
+    /// Reads the exact number of bytes required to fill `buf` from the given offset.
+    ///
+    /// See [`std::os::unix::fs::FileExt::read_exact_at` documentation][std] for details.
+    ///
+    /// [std]: https://doc.rust-lang.org/std/os/unix/fs/trait.FileExt.html#method.read_exact_at
+    fn read_exact_at(&self, mut buf: &mut [u8], mut offset: u64) -> io::Result<()> {
+        while !buf.is_empty() {
+            match self.read_at(buf, offset) {
+                Ok(0) => break,
+                Ok(n) => {
+                    let tmp = buf;
+                    buf = &mut tmp[n..];
+                    offset += n as u64;
+                }
+                Err(ref e) if e.is_interrupted() => {}
+                Err(e) => return Err(e),
+            }
+        }
+        if buf.is_empty() { Ok(()) } else { Err(io::Error::READ_EXACT_EOF) }
+    }
+
+    /// Writes a number of bytes starting from a given offset.
+    ///
+    /// Returns the number of bytes written.
+    ///
+    /// The offset is relative to the start of the file and thus independent
+    /// from the current cursor. The current file cursor is not affected by
+    /// this function.
+    ///
+    /// See [`std::os::unix::fs::FileExt::write_at` documentation][std] for details.
+    ///
+    /// [std]: https://doc.rust-lang.org/std/os/unix/fs/trait.FileExt.html#tymethod.write_at
+    #[doc(alias = "pwrite")]
+    fn write_at(&self, buf: &[u8], offset: u64) -> io::Result<usize>;
+
+    /// Attempts to write an entire buffer starting from a given offset.
+    ///
+    /// See [`std::os::unix::fs::FileExt::write_all_at` documentation][std] for details.
+    ///
+    /// [std]: https://doc.rust-lang.org/std/os/unix/fs/trait.FileExt.html#method.write_all_at
+    fn write_all_at(&self, mut buf: &[u8], mut offset: u64) -> io::Result<()> {
+        while !buf.is_empty() {
+            match self.write_at(buf, offset) {
+                Ok(0) => return Err(io::Error::WRITE_ALL_EOF),
+                Ok(n) => {
+                    buf = &buf[n..];
+                    offset += n as u64;
+                }
+                Err(ref e) if e.is_interrupted() => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FileExt for File {
+    fn read_at(&self, buf: &mut [u8], offset: u64) -> io::Result<usize> {
+        self.0.read_at(buf, offset)
+    }
+    fn write_at(&self, buf: &[u8], offset: u64) -> io::Result<usize> {
+        self.0.write_at(buf, offset)
     }
 }
 
@@ -200,11 +718,14 @@ pub struct OpenOptions {
     pub(crate) append: bool,
     pub(crate) truncate: bool,
     pub(crate) create: bool,
-    #[allow(dead_code)]
     pub(crate) create_new: bool,
     // system-specific
     #[allow(dead_code)]
     pub(crate) mode: u32,
+    #[allow(dead_code)]
+    pub(crate) custom_flags: i32,
+    #[allow(dead_code)] // unused on MIPS
+    pub(crate) platform: sys::fs::OpenOptions,
 }
 
 #[allow(missing_docs)] // TODO
@@ -220,6 +741,8 @@ impl OpenOptions {
             create_new: false,
             // system-specific
             mode: 0o666,
+            custom_flags: 0,
+            platform: sys::fs::OpenOptions::new(),
         }
     }
 
@@ -243,35 +766,471 @@ impl OpenOptions {
         self.create = create;
         self
     }
-    // pub fn create_new(&mut self, create_new: bool) {
-    //     self.create_new = create_new;
-    // }
-
-    // pub fn custom_flags(&mut self, flags: i32) {
-    //     self.custom_flags = flags;
-    // }
-    // pub fn mode(&mut self, mode: u32) {
-    //     self.mode = mode as mode_t;
-    // }
+    /// Sets the option to create a new file, failing if it already exists.
+    ///
+    /// See [`std::fs::OpenOptions::create_new` documentation][std] for details.
+    ///
+    /// [std]: https://doc.rust-lang.org/std/fs/struct.OpenOptions.html#method.create_new
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// The following semihosting calls are currently being used:
+    ///
+    /// | Platform                                                      | Semihosting call                       |
+    /// | ------------------------------------------------------------- | -------------------------------------- |
+    /// | AArch64, Arm, RISC-V, LoongArch, Xtensa (openocd-semihosting) | [SYS_OPEN] (twice; see below)          |
+    /// | MIPS32, MIPS64                                                | UHI_open with `O_CREAT \| O_EXCL`      |
+    ///
+    /// [SYS_OPEN]: https://github.com/ARM-software/abi-aa/blob/2025Q4/semihosting/semihosting.rst#sys-open-0x01
+    ///
+    /// Arm semihosting has no way to atomically check for existence and create
+    /// a file, so on these platforms this first tries to open the file in read-only
+    /// mode, and creates the file only if that fails with [`io::ErrorKind::NotFound`].
+    /// Unlike on other platforms, there is a race: if another program creates the
+    /// file between these two semihosting calls, the file is truncated (or appended to)
+    /// instead of failing with [`io::ErrorKind::AlreadyExists`].
+    ///
+    /// **Disclaimer:** These semihosting calls might change over time.
+    pub fn create_new(&mut self, create_new: bool) -> &mut Self {
+        self.create_new = create_new;
+        self
+    }
 
     pub fn open<P: AsRef<CStr>>(&self, path: P) -> io::Result<File> {
-        sys::fs::open(path.as_ref(), self).map(File)
+        sys::fs::File::open(path.as_ref(), self).map(File)
     }
 }
 
+impl crate::sealed::Sealed for OpenOptions {}
+
 /// Metadata information about a file.
+///
+/// See [`std::fs::Metadata` documentation][std] for details.
+///
+/// [std]: https://doc.rust-lang.org/std/fs/struct.Metadata.html
+///
+/// # Platform-specific behavior
+///
+/// The following semihosting calls are currently being used by [`File::metadata`] to
+/// get the metadata:
+///
+/// | Platform                                                      | Semihosting call |
+/// | ------------------------------------------------------------- | ---------------- |
+/// | AArch64, Arm, RISC-V, LoongArch, Xtensa (openocd-semihosting) | [SYS_FLEN]       |
+/// | MIPS32, MIPS64                                                | UHI_fstat        |
+///
+/// [SYS_FLEN]: https://github.com/ARM-software/abi-aa/blob/2025Q4/semihosting/semihosting.rst#sys-flen-0x0c
+///
+/// **Disclaimer:** These semihosting calls might change over time.
+///
+/// Arm semihosting only provides the file size, so on AArch64, Arm, RISC-V,
+/// LoongArch, and Xtensa:
+///
+/// - The file type is unknown: all methods of [`file_type`](Self::file_type)
+///   (and [`is_file`](Self::is_file), [`is_dir`](Self::is_dir), and
+///   [`is_symlink`](Self::is_symlink)) return `false`, even for a regular file.
+///   Note that directories can also be opened (and so have metadata) on some
+///   hosts.
+/// - Whether the file is readonly is unknown: [`Permissions::readonly`] of
+///   [`permissions`](Self::permissions) returns `false`, even for a readonly
+///   file.
+/// - [`modified`](Self::modified), [`accessed`](Self::accessed), and
+///   [`created`](Self::created) return an error of [`io::ErrorKind::Unsupported`].
 pub struct Metadata(sys::fs::Metadata);
 
 impl Metadata {
+    /// Returns the file type for this metadata.
+    ///
+    /// See [`std::fs::Metadata::file_type` documentation][std] for details.
+    ///
+    /// [std]: https://doc.rust-lang.org/std/fs/struct.Metadata.html#method.file_type
+    #[must_use]
+    pub fn file_type(&self) -> FileType {
+        FileType(self.0.file_type())
+    }
+
+    /// Returns `true` if this metadata is for a directory.
+    ///
+    /// See [`std::fs::Metadata::is_dir` documentation][std] for details.
+    ///
+    /// [std]: https://doc.rust-lang.org/std/fs/struct.Metadata.html#method.is_dir
+    #[must_use]
+    pub fn is_dir(&self) -> bool {
+        self.file_type().is_dir()
+    }
+
+    /// Returns `true` if this metadata is for a regular file.
+    ///
+    /// See [`std::fs::Metadata::is_file` documentation][std] for details.
+    ///
+    /// [std]: https://doc.rust-lang.org/std/fs/struct.Metadata.html#method.is_file
+    #[must_use]
+    pub fn is_file(&self) -> bool {
+        self.file_type().is_file()
+    }
+
+    /// Returns `true` if this metadata is for a symbolic link.
+    ///
+    /// See [`std::fs::Metadata::is_symlink` documentation][std] for details.
+    ///
+    /// [std]: https://doc.rust-lang.org/std/fs/struct.Metadata.html#method.is_symlink
+    #[must_use]
+    pub fn is_symlink(&self) -> bool {
+        self.file_type().is_symlink()
+    }
+
     /// Returns the size of the file, in bytes, this metadata is for.
     #[must_use]
     pub fn len(&self) -> u64 {
         self.0.size()
     }
+
+    /// Returns the permissions of the file this metadata is for.
+    ///
+    /// See [`std::fs::Metadata::permissions` documentation][std] for details.
+    ///
+    /// [std]: https://doc.rust-lang.org/std/fs/struct.Metadata.html#method.permissions
+    #[must_use]
+    pub fn permissions(&self) -> Permissions {
+        Permissions(self.0.permissions())
+    }
+
+    /// Returns the last modification time listed in this metadata.
+    ///
+    /// See [`std::fs::Metadata::modified` documentation][std] for details.
+    ///
+    /// [std]: https://doc.rust-lang.org/std/fs/struct.Metadata.html#method.modified
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error of [`io::ErrorKind::Unsupported`] on
+    /// platforms where this field is not available.
+    #[cfg(feature = "time")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+    pub fn modified(&self) -> io::Result<SystemTime> {
+        self.0.modified().map(SystemTime::from_inner)
+    }
+
+    /// Returns the last access time of this metadata.
+    ///
+    /// See [`std::fs::Metadata::accessed` documentation][std] for details.
+    ///
+    /// [std]: https://doc.rust-lang.org/std/fs/struct.Metadata.html#method.accessed
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error of [`io::ErrorKind::Unsupported`] on
+    /// platforms where this field is not available.
+    #[cfg(feature = "time")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+    pub fn accessed(&self) -> io::Result<SystemTime> {
+        self.0.accessed().map(SystemTime::from_inner)
+    }
+
+    /// Returns the creation time listed in this metadata.
+    ///
+    /// See [`std::fs::Metadata::created` documentation][std] for details.
+    ///
+    /// [std]: https://doc.rust-lang.org/std/fs/struct.Metadata.html#method.created
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error of [`io::ErrorKind::Unsupported`] on
+    /// platforms where this field is not available.
+    ///
+    /// None of the currently supported platforms provide the creation time
+    /// (`st_ctime` of MIPS UHI is the time of the last status change), so this
+    /// currently always returns an error.
+    #[cfg(feature = "time")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+    pub fn created(&self) -> io::Result<SystemTime> {
+        self.0.created().map(SystemTime::from_inner)
+    }
 }
 
 impl fmt::Debug for Metadata {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("Metadata").finish_non_exhaustive()
+        f.debug_struct("Metadata")
+            .field("file_type", &self.file_type())
+            .field("permissions", &self.permissions())
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// A structure representing a type of file with accessors for each file type.
+/// It is returned by [`Metadata::file_type`] method.
+///
+/// See [`std::fs::FileType` documentation][std] for details.
+///
+/// [std]: https://doc.rust-lang.org/std/fs/struct.FileType.html
+///
+/// # Platform-specific behavior
+///
+/// Arm semihosting has no way to query the file type, so on AArch64, Arm,
+/// RISC-V, LoongArch, and Xtensa, the file type is unknown and all methods
+/// return `false`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FileType(sys::fs::FileType);
+
+impl FileType {
+    /// Tests whether this file type represents a directory.
+    ///
+    /// See [`std::fs::FileType::is_dir` documentation][std] for details.
+    ///
+    /// [std]: https://doc.rust-lang.org/std/fs/struct.FileType.html#method.is_dir
+    #[allow(clippy::trivially_copy_pass_by_ref)] // align to std::fs::FileType::is_dir
+    #[must_use]
+    pub fn is_dir(&self) -> bool {
+        self.0.is_dir()
+    }
+
+    /// Tests whether this file type represents a regular file.
+    ///
+    /// See [`std::fs::FileType::is_file` documentation][std] for details.
+    ///
+    /// [std]: https://doc.rust-lang.org/std/fs/struct.FileType.html#method.is_file
+    #[allow(clippy::trivially_copy_pass_by_ref)] // align to std::fs::FileType::is_file
+    #[must_use]
+    pub fn is_file(&self) -> bool {
+        self.0.is_file()
+    }
+
+    /// Tests whether this file type represents a symbolic link.
+    ///
+    /// See [`std::fs::FileType::is_symlink` documentation][std] for details.
+    ///
+    /// [std]: https://doc.rust-lang.org/std/fs/struct.FileType.html#method.is_symlink
+    #[allow(clippy::trivially_copy_pass_by_ref)] // align to std::fs::FileType::is_symlink
+    #[must_use]
+    pub fn is_symlink(&self) -> bool {
+        self.0.is_symlink()
+    }
+
+    /// Returns `true` if this file type is a character device.
+    ///
+    /// See [`std::os::unix::fs::FileTypeExt::is_char_device` documentation][std] for details.
+    ///
+    /// [std]: https://doc.rust-lang.org/std/os/unix/fs/trait.FileTypeExt.html#tymethod.is_char_device
+    #[allow(clippy::trivially_copy_pass_by_ref)] // align to std::os::unix::fs::FileTypeExt::is_char_device
+    #[must_use]
+    pub fn is_char_device(&self) -> bool {
+        self.0.is_char_device()
+    }
+}
+
+impl fmt::Debug for FileType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FileType")
+            .field("is_file", &self.is_file())
+            .field("is_dir", &self.is_dir())
+            .field("is_symlink", &self.is_symlink())
+            .field("is_char_device", &self.is_char_device())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Representation of the various permissions on a file.
+///
+/// See [`std::fs::Permissions` documentation][std] for details.
+///
+/// [std]: https://doc.rust-lang.org/std/fs/struct.Permissions.html
+#[derive(Clone, PartialEq, Eq)]
+pub struct Permissions(sys::fs::Permissions);
+
+impl Permissions {
+    /// Returns `true` if these permissions describe a readonly (unwritable) file.
+    ///
+    /// See [`std::fs::Permissions::readonly` documentation][std] for details.
+    ///
+    /// [std]: https://doc.rust-lang.org/std/fs/struct.Permissions.html#method.readonly
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// Arm semihosting has no way to query the permissions, so on AArch64, Arm,
+    /// RISC-V, LoongArch, and Xtensa, whether the file is readonly is unknown
+    /// and this returns `false`.
+    #[must_use]
+    pub fn readonly(&self) -> bool {
+        self.0.readonly()
+    }
+}
+
+impl fmt::Debug for Permissions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Permissions").field("readonly", &self.readonly()).finish_non_exhaustive()
+    }
+}
+
+/// The size of the buffer used to hold the path of a [`NamedTempFile`],
+/// including the trailing nul.
+const TEMP_PATH_MAX: usize = 256;
+
+/// Creates a new temporary file on the host filesystem.
+///
+/// The file is opened in read-write mode, and is removed when the returned
+/// [`NamedTempFile`] is dropped.
+///
+/// **Note:** Unlike the `tempfile` crate, this is not designed to be secure:
+/// the file names are predictable (see below), and this fails with
+/// [`io::ErrorKind::AlreadyExists`] instead of trying another name if a file
+/// with the chosen name already exists (e.g., a file left by an aborted
+/// previous run, or one created by another program on the host). Do not use
+/// this for files that must not be guessed or tampered with by others on the
+/// host.
+///
+/// # Platform-specific behavior
+///
+/// Each call uses the next number `<N>` from `0` to `255` (wrapping around) as
+/// the id of the file. On AArch64, Arm, RISC-V, LoongArch, and Xtensa, the
+/// file name for the id is obtained from the host by [SYS_TMPNAM], and the
+/// host returns the same name for the same id. If the host rejects SYS_TMPNAM,
+/// and on MIPS32 and MIPS64 (UHI has no equivalent call), the file name is
+/// `semihosting-tmp-<N>.tmp` (`<N>` is zero-padded to three digits) in the
+/// current directory of the host instead.
+///
+/// The file is created as if by [`OpenOptions::create_new`]; see its
+/// documentation for the race on AArch64, Arm, RISC-V, LoongArch, and Xtensa.
+///
+/// The following semihosting calls are currently being used:
+///
+/// | Platform                                                      | Semihosting call                  |
+/// | ------------------------------------------------------------- | --------------------------------- |
+/// | AArch64, Arm, RISC-V, LoongArch, Xtensa (openocd-semihosting) | [SYS_TMPNAM], [SYS_OPEN] (twice)  |
+/// | MIPS32, MIPS64                                                | UHI_open with `O_CREAT \| O_EXCL` |
+///
+/// When the returned [`NamedTempFile`] is dropped, the file is closed by
+/// [SYS_CLOSE] or UHI_close, and then removed by [SYS_REMOVE] or UHI_unlink.
+///
+/// [SYS_TMPNAM]: https://github.com/ARM-software/abi-aa/blob/2025Q4/semihosting/semihosting.rst#sys-tmpnam-0x0d
+/// [SYS_OPEN]: https://github.com/ARM-software/abi-aa/blob/2025Q4/semihosting/semihosting.rst#sys-open-0x01
+/// [SYS_CLOSE]: https://github.com/ARM-software/abi-aa/blob/2025Q4/semihosting/semihosting.rst#sys-close-0x02
+/// [SYS_REMOVE]: https://github.com/ARM-software/abi-aa/blob/2025Q4/semihosting/semihosting.rst#sys-remove-0x0e
+///
+/// **Disclaimer:** These semihosting calls might change over time.
+///
+/// # Errors
+///
+/// Returns an error of [`io::ErrorKind::AlreadyExists`] if a file with the
+/// chosen name already exists.
+#[doc(alias = "tmpfile", alias = "mkstemp")]
+#[doc(alias = "SYS_TMPNAM")] // arm_compat
+pub fn tempfile() -> io::Result<NamedTempFile> {
+    // Only atomic load/store are used, so this works on targets without atomic CAS.
+    // Concurrent calls may pick the same id, in which case all but one of them
+    // fail with AlreadyExists (or race as documented in OpenOptions::create_new).
+    static NEXT_ID: AtomicU8 = AtomicU8::new(0);
+    let id = NEXT_ID.load(Ordering::Relaxed);
+    NEXT_ID.store(id.wrapping_add(1), Ordering::Relaxed);
+
+    let mut path = [0; TEMP_PATH_MAX];
+    let len = match sys::fs::tmpnam(id, &mut path) {
+        Ok(name) => name.to_bytes_with_nul().len(),
+        Err(_) => fallback_temp_name(id, &mut path),
+    };
+    // SAFETY: both sys::fs::tmpnam and fallback_temp_name write a
+    // null-terminated string without interior nul to path[..len].
+    let c_path = unsafe { CStr::from_bytes_with_nul_unchecked(&path[..len]) };
+    let file = OpenOptions::new().read(true).write(true).create_new(true).open(c_path)?;
+    Ok(NamedTempFile { file: ManuallyDrop::new(file), path, len })
+}
+
+/// Writes `semihosting-tmp-<id>.tmp\0` to `buf` and returns its length.
+fn fallback_temp_name(id: u8, buf: &mut [u8; TEMP_PATH_MAX]) -> usize {
+    const PREFIX: &[u8] = b"semihosting-tmp-";
+    const SUFFIX: &[u8] = b".tmp\0";
+    let digits = [b'0' + id / 100, b'0' + id / 10 % 10, b'0' + id % 10];
+    let mut len = 0;
+    for part in [PREFIX, &digits, SUFFIX] {
+        buf[len..len + part.len()].copy_from_slice(part);
+        len += part.len();
+    }
+    len
+}
+
+/// A named temporary file on the host filesystem, created by [`tempfile`].
+///
+/// The file is removed from the host filesystem when this is dropped.
+/// Errors during the removal are ignored; use [`close`](Self::close) to handle them.
+pub struct NamedTempFile {
+    file: ManuallyDrop<File>,
+    path: [u8; TEMP_PATH_MAX],
+    // Length of the path, including the trailing nul.
+    len: usize,
+}
+
+impl NamedTempFile {
+    /// Returns the path of the temporary file.
+    #[must_use]
+    pub fn path(&self) -> &CStr {
+        // SAFETY: path[..len] is a null-terminated string without interior nul (see `tempfile`).
+        unsafe { CStr::from_bytes_with_nul_unchecked(&self.path[..self.len]) }
+    }
+
+    /// Returns a reference to the underlying file.
+    #[must_use]
+    pub fn as_file(&self) -> &File {
+        &self.file
+    }
+
+    /// Returns a mutable reference to the underlying file.
+    #[must_use]
+    pub fn as_file_mut(&mut self) -> &mut File {
+        &mut self.file
+    }
+
+    /// Closes and removes the temporary file, returning the error (if any)
+    /// that occurred while removing it.
+    ///
+    /// This is equivalent to dropping `self`, except that errors are reported.
+    pub fn close(self) -> io::Result<()> {
+        let mut this = ManuallyDrop::new(self);
+        this.close_and_remove()
+    }
+
+    fn close_and_remove(&mut self) -> io::Result<()> {
+        // The file must be closed before removing it, as some hosts (e.g., Windows)
+        // do not allow removing open files.
+        // SAFETY: this is only called once, from `close` or `drop`.
+        unsafe { ManuallyDrop::drop(&mut self.file) }
+        remove_file(self.path())
+    }
+}
+
+impl Drop for NamedTempFile {
+    fn drop(&mut self) {
+        let _ = self.close_and_remove();
+    }
+}
+
+impl fmt::Debug for NamedTempFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NamedTempFile")
+            .field("path", &self.path())
+            .field("file", &*self.file)
+            .finish_non_exhaustive()
+    }
+}
+
+impl io::Read for NamedTempFile {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        io::Read::read(self.as_file_mut(), buf)
+    }
+}
+impl io::Write for NamedTempFile {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        io::Write::write(self.as_file_mut(), buf)
+    }
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        io::Write::flush(self.as_file_mut())
+    }
+}
+impl io::Seek for NamedTempFile {
+    #[inline]
+    fn seek(&mut self, pos: io::SeekFrom) -> io::Result<u64> {
+        io::Seek::seek(self.as_file_mut(), pos)
     }
 }
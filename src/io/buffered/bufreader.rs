@@ -0,0 +1,353 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, string::String, vec, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::str;
+use core::{cmp, fmt};
+
+use super::DEFAULT_BUF_SIZE;
+use crate::io::{self, BufRead, Read, Seek, SeekFrom};
+
+/// Adds buffering to any reader.
+///
+/// The buffer is stored inline, so this type can be used without `alloc`.
+/// See also [`HeapBufReader`] which stores the buffer on the heap.
+///
+/// See [`std::io::BufReader` documentation][std] for details.
+///
+/// [std]: https://doc.rust-lang.org/std/io/struct.BufReader.html
+pub struct BufReader<R, const N: usize = DEFAULT_BUF_SIZE> {
+    buf: Buffer<[u8; N]>,
+    inner: R,
+}
+
+impl<R, const N: usize> BufReader<R, N> {
+    /// Creates a new `BufReader<R, N>` with a buffer of `N` bytes.
+    #[inline]
+    pub const fn new(inner: R) -> Self {
+        Self { buf: Buffer::new([0; N]), inner }
+    }
+}
+
+/// Adds buffering to any reader, with a heap-allocated buffer.
+///
+/// This is the same as [`BufReader`] except that the capacity of the buffer
+/// can be chosen at runtime.
+///
+/// See [`std::io::BufReader` documentation][std] for details.
+///
+/// [std]: https://doc.rust-lang.org/std/io/struct.BufReader.html
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct HeapBufReader<R> {
+    buf: Buffer<Box<[u8]>>,
+    inner: R,
+}
+
+#[cfg(feature = "alloc")]
+impl<R> HeapBufReader<R> {
+    /// Creates a new `HeapBufReader<R>` with a default buffer capacity.
+    ///
+    /// The default is currently 1 KiB, but may change in the future.
+    #[inline]
+    pub fn new(inner: R) -> Self {
+        Self::with_capacity(DEFAULT_BUF_SIZE, inner)
+    }
+
+    /// Creates a new `HeapBufReader<R>` with the specified buffer capacity.
+    pub fn with_capacity(capacity: usize, inner: R) -> Self {
+        Self { buf: Buffer::new(vec![0; capacity].into_boxed_slice()), inner }
+    }
+}
+
+macro_rules! impl_buf_reader {
+    ($name:ident <R $(, const $n:ident)?>) => {
+        impl<R $(, const $n: usize)?> $name<R $(, $n)?> {
+            /// Gets a reference to the underlying reader.
+            ///
+            /// It is inadvisable to directly read from the underlying reader.
+            #[inline]
+            pub fn get_ref(&self) -> &R {
+                &self.inner
+            }
+
+            /// Gets a mutable reference to the underlying reader.
+            ///
+            /// It is inadvisable to directly read from the underlying reader.
+            #[inline]
+            pub fn get_mut(&mut self) -> &mut R {
+                &mut self.inner
+            }
+
+            /// Returns a reference to the internally buffered data.
+            ///
+            /// Unlike `fill_buf`, this will not attempt to fill the buffer if it is empty.
+            #[inline]
+            pub fn buffer(&self) -> &[u8] {
+                self.buf.buffer()
+            }
+
+            /// Returns the number of bytes the internal buffer can hold at once.
+            #[inline]
+            pub fn capacity(&self) -> usize {
+                self.buf.capacity()
+            }
+
+            /// Unwraps this buffered reader, returning the underlying reader.
+            ///
+            /// Note that any leftover data in the internal buffer is lost. Therefore,
+            /// a following read from the underlying reader may lead to data loss.
+            #[inline]
+            pub fn into_inner(self) -> R {
+                self.inner
+            }
+
+            /// Invalidates all data in the internal buffer.
+            #[inline]
+            fn discard_buffer(&mut self) {
+                self.buf.discard_buffer();
+            }
+        }
+        impl<R: Read $(, const $n: usize)?> Read for $name<R $(, $n)?> {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                // If we don't have any buffered data and we're doing a massive read
+                // (larger than our internal buffer), bypass our internal buffer
+                // entirely.
+                if self.buf.pos() == self.buf.filled() && buf.len() >= self.capacity() {
+                    self.discard_buffer();
+                    return self.inner.read(buf);
+                }
+                let mut rem = self.fill_buf()?;
+                let nread = rem.read(buf)?;
+                self.consume(nread);
+                Ok(nread)
+            }
+            // Small read_exacts from a BufReader are extremely common when used with a deserializer.
+            // The default implementation calls read in a loop, which results in surprisingly poor code
+            // generation for the common path where the buffer has enough bytes to fill the passed-in
+            // buffer.
+            fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+                if self.buf.consume_with(buf.len(), |claimed| buf.copy_from_slice(claimed)) {
+                    return Ok(());
+                }
+                io::default_read_exact(self, buf)
+            }
+            // The inner reader might have an optimized `read_to_end`. Drain our buffer and then
+            // delegate to the inner implementation.
+            #[cfg(feature = "alloc")]
+            fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
+                let inner_buf = self.buffer();
+                buf.try_reserve(inner_buf.len())?;
+                buf.extend_from_slice(inner_buf);
+                let nread = inner_buf.len();
+                self.discard_buffer();
+                Ok(nread + self.inner.read_to_end(buf)?)
+            }
+            // The inner reader might have an optimized `read_to_end`. Drain our buffer and then
+            // delegate to the inner implementation.
+            #[cfg(feature = "alloc")]
+            fn read_to_string(&mut self, buf: &mut String) -> io::Result<usize> {
+                // In the general `else` case below we must read bytes into a side buffer, check
+                // that they are valid UTF-8, and then append them to `buf`. This requires a
+                // potentially large memcpy.
+                //
+                // If `buf` is empty--the most common case--we can leverage `append_to_string`
+                // to read directly into `buf`'s internal byte buffer, saving an allocation
+                // and a memcpy.
+                if buf.is_empty() {
+                    // `append_to_string`'s safety relies on the buffer only being appended to since
+                    // it only checks the UTF-8 validity of new data. If there were existing content in
+                    // `buf` then an untrustworthy reader (i.e. `self.inner`) could not only append
+                    // bytes but also modify existing bytes and render them invalid. On the other hand,
+                    // if `buf` is empty then by definition any writes must be appends and
+                    // `append_to_string` will validate all of the new bytes.
+                    // SAFETY: see above.
+                    unsafe { io::append_to_string(buf, |b| self.read_to_end(b)) }
+                } else {
+                    // We cannot append our byte buffer directly onto the `buf` String as there could
+                    // be an incomplete UTF-8 sequence that has only been partially read. We must read
+                    // everything into a side buffer first and then call `from_utf8` on the complete
+                    // buffer.
+                    let mut bytes = Vec::new();
+                    self.read_to_end(&mut bytes)?;
+                    let string = str::from_utf8(&bytes).map_err(|_| io::Error::INVALID_UTF8)?;
+                    buf.try_reserve(string.len())?;
+                    buf.push_str(string);
+                    Ok(string.len())
+                }
+            }
+        }
+
+        impl<R: Read $(, const $n: usize)?> BufRead for $name<R $(, $n)?> {
+            fn fill_buf(&mut self) -> io::Result<&[u8]> {
+                self.buf.fill_buf(&mut self.inner)
+            }
+            #[inline]
+            fn consume(&mut self, amt: usize) {
+                self.buf.consume(amt);
+            }
+        }
+
+        impl<R: Seek $(, const $n: usize)?> Seek for $name<R $(, $n)?> {
+            /// Seek to an offset, in bytes, in the underlying reader.
+            ///
+            /// The position used for seeking with <code>[SeekFrom::Current]\(_)</code> is the
+            /// position the underlying reader would be at if the buffered reader had no
+            /// internal buffer.
+            ///
+            /// Seeking always discards the internal buffer, even if the seek position
+            /// would otherwise fall within it. To seek without discarding the internal
+            /// buffer, use [`Seek::seek_relative`].
+            #[allow(clippy::cast_possible_wrap)]
+            fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+                let result = if let SeekFrom::Current(n) = pos {
+                    let remainder = (self.buf.filled() - self.buf.pos()) as i64;
+                    // it should be safe to assume that remainder fits within an i64 as the alternative
+                    // means we managed to allocate 8 exbibytes and that's absurd.
+                    // But it's not out of the realm of possibility for some weird underlying reader to
+                    // support seeking by i64::MIN so we need to handle underflow when subtracting
+                    // remainder.
+                    if let Some(offset) = n.checked_sub(remainder) {
+                        self.inner.seek(SeekFrom::Current(offset))?
+                    } else {
+                        // seek backwards by our remainder, and then by the offset
+                        self.inner.seek(SeekFrom::Current(-remainder))?;
+                        self.discard_buffer();
+                        self.inner.seek(SeekFrom::Current(n))?
+                    }
+                } else {
+                    // Seeking with Start/End doesn't care about our buffer length.
+                    self.inner.seek(pos)?
+                };
+                self.discard_buffer();
+                Ok(result)
+            }
+            /// Returns the current seek position from the start of the stream.
+            ///
+            /// The value returned is equivalent to `self.seek(SeekFrom::Current(0))`
+            /// but does not flush the internal buffer.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if the position of the inner reader is smaller
+            /// than the amount of buffered data.
+            fn stream_position(&mut self) -> io::Result<u64> {
+                let remainder = (self.buf.filled() - self.buf.pos()) as u64;
+                self.inner.stream_position().map(|pos| {
+                    pos.checked_sub(remainder).expect(
+                        "overflow when subtracting remaining buffer size from inner stream position",
+                    )
+                })
+            }
+            /// Seeks relative to the current position.
+            ///
+            /// If the new position lies within the buffer, the buffer will not be
+            /// flushed, allowing for more efficient seeks.
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            fn seek_relative(&mut self, offset: i64) -> io::Result<()> {
+                let pos = self.buf.pos() as u64;
+                if offset < 0 {
+                    if pos.checked_sub(offset.unsigned_abs()).is_some() {
+                        self.buf.unconsume(offset.unsigned_abs() as usize);
+                        return Ok(());
+                    }
+                } else if let Some(new_pos) = pos.checked_add(offset as u64) {
+                    if new_pos <= self.buf.filled() as u64 {
+                        self.buf.consume(offset as usize);
+                        return Ok(());
+                    }
+                }
+                self.seek(SeekFrom::Current(offset)).map(|_| ())
+            }
+        }
+
+        impl<R: fmt::Debug $(, const $n: usize)?> fmt::Debug for $name<R $(, $n)?> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct(stringify!($name))
+                    .field("reader", &self.inner)
+                    .field(
+                        "buffer",
+                        &format_args!("{}/{}", self.buf.filled() - self.buf.pos(), self.capacity()),
+                    )
+                    .finish()
+            }
+        }
+    };
+}
+impl_buf_reader!(BufReader<R, const N>);
+#[cfg(feature = "alloc")]
+impl_buf_reader!(HeapBufReader<R>);
+
+// Based on std's bufreader::buffer::Buffer.
+struct Buffer<B> {
+    buf: B,
+    // The current seek offset into `buf`, must always be <= `filled`.
+    pos: usize,
+    // Each call to `fill_buf` sets `filled` to indicate how many bytes at the start of `buf` are
+    // initialized with bytes from a read.
+    filled: usize,
+}
+
+impl<B: AsRef<[u8]> + AsMut<[u8]>> Buffer<B> {
+    #[inline]
+    const fn new(buf: B) -> Self {
+        Self { buf, pos: 0, filled: 0 }
+    }
+    #[inline]
+    fn buffer(&self) -> &[u8] {
+        &self.buf.as_ref()[self.pos..self.filled]
+    }
+    #[inline]
+    fn capacity(&self) -> usize {
+        self.buf.as_ref().len()
+    }
+    #[inline]
+    fn filled(&self) -> usize {
+        self.filled
+    }
+    #[inline]
+    fn pos(&self) -> usize {
+        self.pos
+    }
+    #[inline]
+    fn discard_buffer(&mut self) {
+        self.pos = 0;
+        self.filled = 0;
+    }
+    #[inline]
+    fn consume(&mut self, amt: usize) {
+        self.pos = cmp::min(self.pos + amt, self.filled);
+    }
+    /// Remove bytes that have already been read from the buffer.
+    #[inline]
+    fn unconsume(&mut self, amt: usize) {
+        self.pos = self.pos.saturating_sub(amt);
+    }
+    /// If there are `amt` bytes available in the buffer, pass a slice containing those bytes to
+    /// `visitor` and return true. If there are not enough bytes available, return false.
+    #[inline]
+    fn consume_with<V: FnMut(&[u8])>(&mut self, amt: usize, mut visitor: V) -> bool {
+        if let Some(claimed) = self.buffer().get(..amt) {
+            visitor(claimed);
+            // If the indexing into self.buffer() succeeds, amt must be a valid increment.
+            self.pos += amt;
+            true
+        } else {
+            false
+        }
+    }
+    fn fill_buf<R: Read>(&mut self, reader: &mut R) -> io::Result<&[u8]> {
+        // If we've reached the end of our internal buffer then we need to fetch
+        // some more data from the reader.
+        // Branch using `>=` instead of the more correct `==`
+        // to tell the compiler that the pos..cap slice is always valid.
+        if self.pos >= self.filled {
+            debug_assert_eq!(self.pos, self.filled);
+            let n = reader.read(self.buf.as_mut())?;
+            self.pos = 0;
+            self.filled = n;
+        }
+        Ok(self.buffer())
+    }
+}
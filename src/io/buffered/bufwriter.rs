@@ -0,0 +1,268 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, vec};
+use core::{fmt, mem::ManuallyDrop, ptr};
+
+use super::{DEFAULT_BUF_SIZE, IntoInnerError};
+use crate::io::{self, Seek, SeekFrom, Write};
+
+/// Wraps a writer and buffers its output.
+///
+/// The buffer is stored inline, so this type can be used without `alloc`.
+/// See also [`HeapBufWriter`] which stores the buffer on the heap.
+///
+/// See [`std::io::BufWriter` documentation][std] for details.
+///
+/// [std]: https://doc.rust-lang.org/std/io/struct.BufWriter.html
+pub struct BufWriter<W: Write, const N: usize = DEFAULT_BUF_SIZE> {
+    buf: [u8; N],
+    len: usize,
+    // #30888: If the inner writer panics in a call to write, we don't want to
+    // write the buffered data a second time in BufWriter's destructor. This
+    // flag tells the Drop impl if it should skip the flush.
+    panicked: bool,
+    inner: W,
+}
+
+impl<W: Write, const N: usize> BufWriter<W, N> {
+    /// Creates a new `BufWriter<W, N>` with a buffer of `N` bytes.
+    #[inline]
+    pub const fn new(inner: W) -> Self {
+        Self { buf: [0; N], len: 0, panicked: false, inner }
+    }
+}
+
+/// Wraps a writer and buffers its output, with a heap-allocated buffer.
+///
+/// This is the same as [`BufWriter`] except that the capacity of the buffer
+/// can be chosen at runtime.
+///
+/// See [`std::io::BufWriter` documentation][std] for details.
+///
+/// [std]: https://doc.rust-lang.org/std/io/struct.BufWriter.html
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct HeapBufWriter<W: Write> {
+    buf: Box<[u8]>,
+    len: usize,
+    panicked: bool,
+    inner: W,
+}
+
+#[cfg(feature = "alloc")]
+impl<W: Write> HeapBufWriter<W> {
+    /// Creates a new `HeapBufWriter<W>` with a default buffer capacity.
+    ///
+    /// The default is currently 1 KiB, but may change in the future.
+    #[inline]
+    pub fn new(inner: W) -> Self {
+        Self::with_capacity(DEFAULT_BUF_SIZE, inner)
+    }
+
+    /// Creates a new `HeapBufWriter<W>` with at least the specified buffer capacity.
+    pub fn with_capacity(capacity: usize, inner: W) -> Self {
+        Self { buf: vec![0; capacity].into_boxed_slice(), len: 0, panicked: false, inner }
+    }
+}
+
+macro_rules! impl_buf_writer {
+    ($name:ident <W $(, const $n:ident)?>) => {
+        impl<W: Write $(, const $n: usize)?> $name<W $(, $n)?> {
+            /// Gets a reference to the underlying writer.
+            #[inline]
+            pub fn get_ref(&self) -> &W {
+                &self.inner
+            }
+
+            /// Gets a mutable reference to the underlying writer.
+            ///
+            /// It is inadvisable to directly write to the underlying writer.
+            #[inline]
+            pub fn get_mut(&mut self) -> &mut W {
+                &mut self.inner
+            }
+
+            /// Returns a reference to the internally buffered data.
+            #[inline]
+            pub fn buffer(&self) -> &[u8] {
+                &self.buf[..self.len]
+            }
+
+            /// Returns the number of bytes the internal buffer can hold without flushing.
+            #[inline]
+            pub fn capacity(&self) -> usize {
+                self.buf.len()
+            }
+
+            /// Unwraps this buffered writer, returning the underlying writer.
+            ///
+            /// The buffer is written out before returning the writer.
+            ///
+            /// # Errors
+            ///
+            /// An [`Err`] will be returned if an error occurs while flushing the buffer.
+            pub fn into_inner(mut self) -> Result<W, IntoInnerError<Self>> {
+                match self.flush_buf() {
+                    Err(e) => Err(IntoInnerError::new(self, e)),
+                    Ok(()) => {
+                        let this = ManuallyDrop::new(self);
+                        let (_buf, inner) =
+                            // SAFETY: `this` is never dropped, so each field is read
+                            // (and dropped) exactly once.
+                            unsafe { (ptr::read(&this.buf), ptr::read(&this.inner)) };
+                        Ok(inner)
+                    }
+                }
+            }
+
+            /// Send data in our local buffer into the inner writer, looping as
+            /// necessary until either it's all been sent or an error occurs.
+            ///
+            /// Because all the data in the buffer has been reported to our owner as
+            /// "successfully written" (by returning nonzero success values from
+            /// `write`), any 0-length writes from `inner` must be reported as i/o
+            /// errors from this method.
+            fn flush_buf(&mut self) -> io::Result<()> {
+                let mut written = 0;
+                let mut ret = Ok(());
+                while written < self.len {
+                    self.panicked = true;
+                    let r = self.inner.write(&self.buf[written..self.len]);
+                    self.panicked = false;
+
+                    match r {
+                        Ok(0) => {
+                            ret = Err(const_error!(
+                                io::ErrorKind::WriteZero,
+                                "failed to write the buffered data",
+                            ));
+                            break;
+                        }
+                        Ok(n) => written += n,
+                        Err(ref e) if e.is_interrupted() => {}
+                        Err(e) => {
+                            ret = Err(e);
+                            break;
+                        }
+                    }
+                }
+                // Remove the data that has been written, even if an error occurred.
+                if written > 0 {
+                    self.buf.copy_within(written..self.len, 0);
+                    self.len -= written;
+                }
+                ret
+            }
+
+            #[inline]
+            fn spare_capacity(&self) -> usize {
+                self.buf.len() - self.len
+            }
+
+            /// Appends `buf` to the internal buffer. The caller must ensure that it fits.
+            #[inline]
+            fn push(&mut self, buf: &[u8]) {
+                self.buf[self.len..self.len + buf.len()].copy_from_slice(buf);
+                self.len += buf.len();
+            }
+
+            // Slow path of `write`: flushes the buffer if `buf` doesn't fit, and
+            // bypasses the buffer if `buf` is larger than the whole buffer.
+            #[cold]
+            fn write_cold(&mut self, buf: &[u8]) -> io::Result<usize> {
+                if buf.len() > self.spare_capacity() {
+                    self.flush_buf()?;
+                }
+                if buf.len() >= self.buf.len() {
+                    self.panicked = true;
+                    let r = self.inner.write(buf);
+                    self.panicked = false;
+                    r
+                } else {
+                    self.push(buf);
+                    Ok(buf.len())
+                }
+            }
+
+            // Slow path of `write_all`.
+            #[cold]
+            fn write_all_cold(&mut self, buf: &[u8]) -> io::Result<()> {
+                if buf.len() > self.spare_capacity() {
+                    self.flush_buf()?;
+                }
+                if buf.len() >= self.buf.len() {
+                    self.panicked = true;
+                    let r = self.inner.write_all(buf);
+                    self.panicked = false;
+                    r
+                } else {
+                    self.push(buf);
+                    Ok(())
+                }
+            }
+        }
+
+        impl<W: Write $(, const $n: usize)?> Write for $name<W $(, $n)?> {
+            #[inline]
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                // Use < instead of <= to avoid a needless trip through the buffer in some cases.
+                // See `write_cold` for details.
+                if buf.len() < self.spare_capacity() {
+                    self.push(buf);
+                    Ok(buf.len())
+                } else {
+                    self.write_cold(buf)
+                }
+            }
+            #[inline]
+            fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+                // Normally, `write_all` just calls `write` in a loop. We can do better
+                // by calling `self.get_mut().write_all()` directly, which avoids
+                // round trips through the buffer in the event of a series of partial
+                // writes in some circumstances.
+                if buf.len() < self.spare_capacity() {
+                    self.push(buf);
+                    Ok(())
+                } else {
+                    self.write_all_cold(buf)
+                }
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                self.flush_buf()?;
+                self.get_mut().flush()
+            }
+        }
+
+        impl<W: Write + Seek $(, const $n: usize)?> Seek for $name<W $(, $n)?> {
+            /// Seek to the offset, in bytes, in the underlying writer.
+            ///
+            /// Seeking always writes out the internal buffer before seeking.
+            fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+                self.flush_buf()?;
+                self.get_mut().seek(pos)
+            }
+        }
+
+        impl<W: Write $(, const $n: usize)?> Drop for $name<W $(, $n)?> {
+            fn drop(&mut self) {
+                if !self.panicked {
+                    // dtors should not panic, so we ignore a failed flush
+                    let _r = self.flush_buf();
+                }
+            }
+        }
+
+        impl<W: Write + fmt::Debug $(, const $n: usize)?> fmt::Debug for $name<W $(, $n)?> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct(stringify!($name))
+                    .field("writer", &self.inner)
+                    .field("buffer", &format_args!("{}/{}", self.len, self.capacity()))
+                    .finish()
+            }
+        }
+    };
+}
+impl_buf_writer!(BufWriter<W, const N>);
+#[cfg(feature = "alloc")]
+impl_buf_writer!(HeapBufWriter<W>);
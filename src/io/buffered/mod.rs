@@ -0,0 +1,340 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// Based on nightly-2025-02-19's std::io::buffered module.
+//
+// Unlike std, the buffer is stored inline (`[u8; N]`) by default so that these
+// types can be used without `alloc`. Heap-allocated variants whose capacity is
+// chosen at runtime are available when the `alloc` feature is enabled.
+
+mod bufreader;
+mod bufwriter;
+
+use core::fmt;
+
+pub use self::{bufreader::BufReader, bufwriter::BufWriter};
+#[cfg(feature = "alloc")]
+pub use self::{bufreader::HeapBufReader, bufwriter::HeapBufWriter};
+use crate::io;
+
+/// The default buffer capacity of [`BufReader`], [`BufWriter`], and their heap
+/// allocated variants.
+///
+/// This is smaller than std's default (8 KiB) since the buffer of [`BufReader`]
+/// and [`BufWriter`] is usually on the stack.
+pub(crate) const DEFAULT_BUF_SIZE: usize = 1024;
+
+/// An error returned by [`BufWriter::into_inner`] which combines an error that
+/// happened while writing out the buffer, and the buffered writer object
+/// which may be used to recover from the condition.
+///
+/// See [`std::io::IntoInnerError` documentation][std] for details.
+///
+/// [std]: https://doc.rust-lang.org/std/io/struct.IntoInnerError.html
+#[derive(Debug)]
+pub struct IntoInnerError<W>(W, io::Error);
+
+impl<W> IntoInnerError<W> {
+    #[inline]
+    fn new(writer: W, error: io::Error) -> Self {
+        Self(writer, error)
+    }
+
+    /// Returns the error which caused the call to [`BufWriter::into_inner()`]
+    /// to fail.
+    ///
+    /// This error was returned when attempting to write the internal buffer.
+    #[inline]
+    #[must_use]
+    pub fn error(&self) -> &io::Error {
+        &self.1
+    }
+
+    /// Returns the buffered writer instance which generated the error.
+    ///
+    /// The returned object can be used for error recovery, such as
+    /// re-inspecting the buffer.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> W {
+        self.0
+    }
+
+    /// Consumes the [`IntoInnerError`] and returns the error which caused the call to
+    /// [`BufWriter::into_inner()`] to fail. Unlike `error`, this can be used to
+    /// obtain ownership of the underlying error.
+    #[inline]
+    #[must_use]
+    pub fn into_error(self) -> io::Error {
+        self.1
+    }
+
+    /// Consumes the [`IntoInnerError`] and returns the error which caused the call to
+    /// [`BufWriter::into_inner()`] to fail, and the underlying writer.
+    #[inline]
+    #[must_use]
+    pub fn into_parts(self) -> (io::Error, W) {
+        (self.1, self.0)
+    }
+}
+
+impl<W> From<IntoInnerError<W>> for io::Error {
+    fn from(iie: IntoInnerError<W>) -> Self {
+        iie.1
+    }
+}
+
+impl<W> fmt::Display for IntoInnerError<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.error(), f)
+    }
+}
+
+#[cfg(not(semihosting_no_error_in_core))]
+impl<W: fmt::Debug> core::error::Error for IntoInnerError<W> {}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "alloc")]
+    use alloc::vec::Vec;
+
+    use crate::io::{
+        BufRead as _, BufReader, BufWriter, ErrorKind, Read as _, Seek as _, SeekFrom, Write as _,
+    };
+
+    struct ShortReader<'a> {
+        data: &'a [u8],
+        reads: usize,
+    }
+    impl crate::io::Read for ShortReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> crate::io::Result<usize> {
+            self.reads += 1;
+            let n = buf.len().min(self.data.len()).min(3);
+            buf[..n].copy_from_slice(&self.data[..n]);
+            self.data = &self.data[n..];
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn buf_reader() {
+        let inner: &[u8] = &[5, 6, 7, 0, 1, 2, 3, 4];
+        let mut reader = BufReader::<_, 2>::new(inner);
+
+        let mut buf = [0, 0, 0];
+        assert_eq!(reader.read(&mut buf).unwrap(), 3);
+        assert_eq!(buf, [5, 6, 7]);
+        assert_eq!(reader.buffer(), []);
+
+        let mut buf = [0];
+        assert_eq!(reader.read(&mut buf).unwrap(), 1);
+        assert_eq!(buf, [0]);
+        assert_eq!(reader.buffer(), [1]);
+
+        let mut buf = [0, 0, 0];
+        assert_eq!(reader.read(&mut buf).unwrap(), 1);
+        assert_eq!(buf, [1, 0, 0]);
+        assert_eq!(reader.buffer(), []);
+
+        let mut buf = [0, 0, 0];
+        reader.read_exact(&mut buf).unwrap();
+        assert_eq!(buf, [2, 3, 4]);
+        assert_eq!(reader.read(&mut buf).unwrap(), 0);
+        assert_eq!(reader.capacity(), 2);
+    }
+
+    #[test]
+    fn buf_reader_reduces_reads() {
+        let mut reader = BufReader::<_, 16>::new(ShortReader { data: b"abcdefgh", reads: 0 });
+        let mut buf = [0; 1];
+        for &b in b"abc" {
+            reader.read_exact(&mut buf).unwrap();
+            assert_eq!(buf, [b]);
+        }
+        assert_eq!(reader.get_ref().reads, 1);
+    }
+
+    struct SeekReader<'a> {
+        data: &'a [u8],
+        pos: usize,
+    }
+    impl crate::io::Read for SeekReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> crate::io::Result<usize> {
+            let mut rem = &self.data[self.pos..];
+            let n = rem.read(buf)?;
+            self.pos += n;
+            Ok(n)
+        }
+    }
+    impl crate::io::Seek for SeekReader<'_> {
+        #[allow(
+            clippy::cast_possible_truncation,
+            clippy::cast_possible_wrap,
+            clippy::cast_sign_loss
+        )]
+        fn seek(&mut self, pos: SeekFrom) -> crate::io::Result<u64> {
+            self.pos = match pos {
+                SeekFrom::Start(n) => n as usize,
+                SeekFrom::End(n) => (self.data.len() as i64 + n) as usize,
+                SeekFrom::Current(n) => (self.pos as i64 + n) as usize,
+            };
+            Ok(self.pos as u64)
+        }
+    }
+
+    #[test]
+    fn buf_reader_seek() {
+        let mut reader =
+            BufReader::<_, 2>::new(SeekReader { data: &[0, 1, 2, 3, 4, 5, 6], pos: 0 });
+        assert_eq!(reader.fill_buf().unwrap(), [0, 1]);
+        reader.consume(1);
+        assert_eq!(reader.stream_position().unwrap(), 1);
+        reader.seek_relative(1).unwrap();
+        assert_eq!(reader.buffer(), []);
+        reader.seek_relative(-1).unwrap();
+        assert_eq!(reader.buffer(), [1]);
+        assert_eq!(reader.seek(SeekFrom::Current(2)).unwrap(), 3);
+        assert_eq!(reader.buffer(), []);
+        assert_eq!(reader.fill_buf().unwrap(), [3, 4]);
+        reader.seek_relative(3).unwrap();
+        assert_eq!(reader.get_ref().pos, 6);
+        assert_eq!(reader.fill_buf().unwrap(), [6]);
+        assert_eq!(reader.seek(SeekFrom::Start(1)).unwrap(), 1);
+        assert_eq!(reader.fill_buf().unwrap(), [1, 2]);
+    }
+
+    #[test]
+    fn buf_read() {
+        let mut reader = BufReader::<_, 4>::new(&b"ab\ncdefg\n\nh"[..]);
+        assert_eq!(reader.fill_buf().unwrap(), b"ab\nc");
+        reader.consume(1);
+        assert_eq!(reader.fill_buf().unwrap(), b"b\nc");
+        assert_eq!(reader.skip_until(b'\n').unwrap(), 2);
+        let mut buf = [0; 8];
+        assert_eq!(reader.read_line_into(&mut buf).unwrap(), "cdefg\n");
+        assert_eq!(reader.read_line_into(&mut buf).unwrap(), "\n");
+        assert_eq!(reader.read_line_into(&mut buf).unwrap(), "h");
+        assert_eq!(reader.read_line_into(&mut buf).unwrap(), "");
+        assert_eq!(reader.skip_until(b'\n').unwrap(), 0);
+
+        let mut reader: &[u8] = b"abcd\nef";
+        let mut buf = [0; 4];
+        assert_eq!(reader.read_line_into(&mut buf).unwrap_err().kind(), ErrorKind::InvalidData);
+        assert_eq!(reader, b"\nef");
+        // A last line without a newline that exactly fills the buffer.
+        let mut reader: &[u8] = b"abcd";
+        assert_eq!(reader.read_line_into(&mut buf).unwrap(), "abcd");
+        assert_eq!(reader.read_line_into(&mut buf).unwrap(), "");
+        let mut reader = BufReader::<_, 2>::new(&b"ef\nghij"[..]);
+        assert_eq!(reader.read_line_into(&mut buf).unwrap(), "ef\n");
+        assert_eq!(reader.read_line_into(&mut buf).unwrap(), "ghij");
+        assert_eq!(reader.read_line_into(&mut buf).unwrap(), "");
+        assert_eq!(reader.read_line_into(&mut [0; 0]).unwrap(), "");
+        let mut reader: &[u8] = b"a";
+        assert_eq!(reader.read_line_into(&mut [0; 0]).unwrap_err().kind(), ErrorKind::InvalidData);
+        let mut reader: &[u8] = b"\xff\n";
+        assert_eq!(reader.read_line_into(&mut buf).unwrap_err().kind(), ErrorKind::InvalidData);
+        assert_eq!(reader, b"");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn buf_read_alloc() {
+        use alloc::{collections::VecDeque, string::String};
+
+        let mut reader = BufReader::<_, 2>::new(&b"ab\r\ncd\n\xff\ne"[..]);
+        let mut s = String::from("x");
+        assert_eq!(reader.read_line(&mut s).unwrap(), 4);
+        assert_eq!(s, "xab\r\n");
+        let mut v = Vec::new();
+        assert_eq!(reader.read_until(b'd', &mut v).unwrap(), 2);
+        assert_eq!(v, b"cd");
+        assert_eq!(reader.skip_until(b'\n').unwrap(), 1);
+        assert_eq!(reader.read_line(&mut s).unwrap_err().kind(), ErrorKind::InvalidData);
+        assert_eq!(s, "xab\r\n");
+        assert_eq!(reader.read_line(&mut s).unwrap(), 1);
+        assert_eq!(s, "xab\r\ne");
+
+        let lines: Vec<_> = (&b"a\nb\r\n\nc"[..]).lines().map(Result::unwrap).collect();
+        assert_eq!(lines, ["a", "b", "", "c"]);
+        let split: Vec<_> =
+            crate::io::BufRead::split(&b"a,b,,c,"[..], b',').map(Result::unwrap).collect();
+        assert_eq!(split, [&b"a"[..], b"b", b"", b"c"]);
+
+        let mut deque = VecDeque::from(Vec::from(&b"ab\ncd"[..]));
+        let lines: Vec<_> = (&mut deque).lines().map(Result::unwrap).collect();
+        assert_eq!(lines, ["ab", "cd"]);
+        assert!(deque.is_empty());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn buf_writer() {
+        let mut writer = BufWriter::<_, 2>::new(Vec::<u8>::new());
+
+        writer.write_all(&[0, 1]).unwrap();
+        assert_eq!(writer.buffer(), []);
+        assert_eq!(*writer.get_ref(), [0, 1]);
+
+        writer.write_all(&[2]).unwrap();
+        assert_eq!(writer.buffer(), [2]);
+        assert_eq!(*writer.get_ref(), [0, 1]);
+
+        writer.write_all(&[3]).unwrap();
+        assert_eq!(writer.buffer(), [2, 3]);
+        assert_eq!(*writer.get_ref(), [0, 1]);
+
+        writer.flush().unwrap();
+        assert_eq!(writer.buffer(), []);
+        assert_eq!(*writer.get_ref(), [0, 1, 2, 3]);
+
+        writer.write_all(&[4]).unwrap();
+        writer.write_all(&[5]).unwrap();
+        assert_eq!(writer.buffer(), [4, 5]);
+
+        writer.write_all(&[6, 7, 8, 9]).unwrap();
+        assert_eq!(writer.buffer(), []);
+        assert_eq!(*writer.get_ref(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+        writer.write_all(&[10]).unwrap();
+        assert_eq!(writer.into_inner().unwrap(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    }
+
+    #[test]
+    fn buf_writer_drop_flushes() {
+        let mut out = [0; 4];
+        {
+            let mut writer = BufWriter::<_, 8>::new(&mut out[..]);
+            writer.write_all(b"ab").unwrap();
+            writer.write_all(b"cd").unwrap();
+        }
+        assert_eq!(&out, b"abcd");
+    }
+
+    #[test]
+    fn buf_writer_into_inner_error() {
+        let mut out = [0; 2];
+        let mut writer = BufWriter::<_, 8>::new(&mut out[..]);
+        writer.write_all(b"abc").unwrap();
+        let err = writer.into_inner().unwrap_err();
+        assert_eq!(err.error().kind(), ErrorKind::WriteZero);
+        assert_eq!(err.into_inner().buffer(), b"c");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn heap() {
+        use crate::io::{HeapBufReader, HeapBufWriter};
+        let inner: &[u8] = b"hello";
+        let mut reader = HeapBufReader::with_capacity(3, inner);
+        let mut buf = [0; 5];
+        reader.read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"hello");
+        assert_eq!(reader.capacity(), 3);
+
+        let mut writer = HeapBufWriter::with_capacity(3, Vec::<u8>::new());
+        writer.write_all(b"he").unwrap();
+        assert_eq!(writer.buffer(), b"he");
+        writer.write_all(b"llo").unwrap();
+        assert_eq!(writer.into_inner().unwrap(), b"hello");
+    }
+}
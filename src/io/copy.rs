@@ -0,0 +1,84 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// Based on nightly-2025-02-19's std::io::copy module.
+//
+// Unlike std, we cannot use specialization to select the copy strategy, so
+// io::copy uses the same stack buffer for all readers and writers. fs::copy,
+// which knows that both ends are host files, uses `file_copy` instead.
+
+#[cfg(feature = "fs")]
+use crate::fs;
+use crate::io::{Read, Result, Write, buffered::DEFAULT_BUF_SIZE};
+
+/// The buffer size used by [`file_copy`].
+///
+/// Each read and write of a host file is a semihosting call, which is expensive,
+/// so a larger buffer than [`DEFAULT_BUF_SIZE`] is used to reduce the number of
+/// round-trips to the host.
+#[cfg(feature = "fs")]
+const FILE_COPY_BUF_SIZE: usize = 4 * 1024;
+
+/// Copies the entire contents of a reader into a writer.
+///
+/// See [`std::io::copy` documentation][std] for details.
+///
+/// # Platform-specific behavior
+///
+/// Data is copied through a stack buffer, which is currently 1 KiB.
+/// When copying between host files, prefer [`fs::copy`](crate::fs::copy),
+/// which uses a larger buffer to reduce the number of semihosting calls.
+///
+/// [std]: https://doc.rust-lang.org/std/io/fn.copy.html
+pub fn copy<R: ?Sized + Read, W: ?Sized + Write>(reader: &mut R, writer: &mut W) -> Result<u64> {
+    let mut buf = [0_u8; DEFAULT_BUF_SIZE];
+    copy_with_buf(reader, writer, &mut buf, 0)
+}
+
+/// Copies the entire contents of a host file into another host file, using a
+/// buffer of [`FILE_COPY_BUF_SIZE`] bytes.
+///
+/// The first chunk is read from `reader` before `create_writer` is called, so
+/// if `reader` cannot be read (e.g., it is a directory), the destination is
+/// left untouched.
+// Not inlined so that the larger buffer is only placed on the stack when it is used.
+#[cfg(feature = "fs")]
+#[inline(never)]
+pub(crate) fn file_copy(
+    reader: &mut fs::File,
+    create_writer: impl FnOnce() -> Result<fs::File>,
+) -> Result<u64> {
+    let mut buf = [0_u8; FILE_COPY_BUF_SIZE];
+    let n = loop {
+        match reader.read(&mut buf) {
+            Ok(n) => break n,
+            Err(ref e) if e.is_interrupted() => {}
+            Err(e) => return Err(e),
+        }
+    };
+    let mut writer = create_writer()?;
+    if n == 0 {
+        return Ok(0);
+    }
+    writer.write_all(&buf[..n])?;
+    copy_with_buf(reader, &mut writer, &mut buf, n as u64)
+}
+
+/// Copies the rest of `reader` into `writer` through `buf`, and returns the
+/// total number of bytes copied, including `len` bytes that were already copied.
+fn copy_with_buf<R: ?Sized + Read, W: ?Sized + Write>(
+    reader: &mut R,
+    writer: &mut W,
+    buf: &mut [u8],
+    mut len: u64,
+) -> Result<u64> {
+    loop {
+        let n = match reader.read(buf) {
+            Ok(0) => return Ok(len),
+            Ok(n) => n,
+            Err(ref e) if e.is_interrupted() => continue,
+            Err(e) => return Err(e),
+        };
+        writer.write_all(&buf[..n])?;
+        len += n as u64;
+    }
+}
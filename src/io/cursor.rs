@@ -0,0 +1,365 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// Based on nightly-2025-02-19's std::io::cursor module.
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::cmp;
+
+use crate::io::{self, BufRead, Read, Seek, SeekFrom, Write};
+
+/// A `Cursor` wraps an in-memory buffer and provides it with a
+/// [`Seek`] implementation.
+///
+/// This is useful for testing code written against the [`io`](crate::io) traits
+/// without the host filesystem.
+///
+/// See [`std::io::Cursor` documentation][std] for details.
+///
+/// [std]: https://doc.rust-lang.org/std/io/struct.Cursor.html
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct Cursor<T> {
+    inner: T,
+    pos: u64,
+}
+
+impl<T> Cursor<T> {
+    /// Creates a new cursor wrapping the provided underlying in-memory buffer.
+    ///
+    /// Cursor initial position is `0` even if underlying buffer (e.g., `Vec`)
+    /// is not empty. So writing to cursor starts with overwriting `Vec`
+    /// content, not with appending to it.
+    pub const fn new(inner: T) -> Self {
+        Self { pos: 0, inner }
+    }
+
+    /// Consumes this cursor, returning the underlying value.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    /// Gets a reference to the underlying value in this cursor.
+    pub const fn get_ref(&self) -> &T {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying value in this cursor.
+    ///
+    /// Care should be taken to avoid modifying the internal I/O state of the
+    /// underlying value as it may corrupt this cursor's position.
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Returns the current position of this cursor.
+    pub const fn position(&self) -> u64 {
+        self.pos
+    }
+
+    /// Sets the position of this cursor.
+    pub fn set_position(&mut self, pos: u64) {
+        self.pos = pos;
+    }
+}
+
+impl<T: AsRef<[u8]>> Cursor<T> {
+    /// Returns the remaining slice.
+    fn remaining_slice(&self) -> &[u8] {
+        let inner = self.inner.as_ref();
+        let start_pos = cmp::min(self.pos, inner.len() as u64);
+        #[allow(clippy::cast_possible_truncation)] // start_pos <= inner.len()
+        &inner[start_pos as usize..]
+    }
+}
+
+impl<T: Clone> Clone for Cursor<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { inner: self.inner.clone(), pos: self.pos }
+    }
+    #[inline]
+    fn clone_from(&mut self, other: &Self) {
+        self.inner.clone_from(&other.inner);
+        self.pos = other.pos;
+    }
+}
+
+impl<T: AsRef<[u8]>> Seek for Cursor<T> {
+    #[allow(clippy::cast_sign_loss)]
+    fn seek(&mut self, style: SeekFrom) -> io::Result<u64> {
+        let (base_pos, offset) = match style {
+            SeekFrom::Start(n) => {
+                self.pos = n;
+                return Ok(n);
+            }
+            SeekFrom::End(n) => (self.inner.as_ref().len() as u64, n),
+            SeekFrom::Current(n) => (self.pos, n),
+        };
+        let new_pos = if offset >= 0 {
+            base_pos.checked_add(offset as u64)
+        } else {
+            base_pos.checked_sub(offset.wrapping_neg() as u64)
+        };
+        match new_pos {
+            Some(n) => {
+                self.pos = n;
+                Ok(self.pos)
+            }
+            None => Err(io::Error::SEEK_NEGATIVE_OR_OVERFLOW),
+        }
+    }
+    #[inline]
+    fn stream_position(&mut self) -> io::Result<u64> {
+        Ok(self.pos)
+    }
+}
+
+impl<T: AsRef<[u8]>> Read for Cursor<T> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = Read::read(&mut self.remaining_slice(), buf)?;
+        self.pos += n as u64;
+        Ok(n)
+    }
+    fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+        let result = Read::read_exact(&mut self.remaining_slice(), buf);
+        match result {
+            Ok(()) => self.pos += buf.len() as u64,
+            // The only possible error condition is EOF, so place the cursor at "EOF"
+            Err(_) => self.pos = self.inner.as_ref().len() as u64,
+        }
+        result
+    }
+    #[cfg(feature = "alloc")]
+    fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
+        let content = self.remaining_slice();
+        let len = content.len();
+        buf.try_reserve(len)?;
+        buf.extend_from_slice(content);
+        self.pos += len as u64;
+        Ok(len)
+    }
+    #[cfg(feature = "alloc")]
+    fn read_to_string(&mut self, buf: &mut String) -> io::Result<usize> {
+        let content =
+            core::str::from_utf8(self.remaining_slice()).map_err(|_| io::Error::INVALID_UTF8)?;
+        let len = content.len();
+        buf.try_reserve(len)?;
+        buf.push_str(content);
+        self.pos += len as u64;
+        Ok(len)
+    }
+}
+
+impl<T: AsRef<[u8]>> BufRead for Cursor<T> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        Ok(self.remaining_slice())
+    }
+    fn consume(&mut self, amt: usize) {
+        self.pos += amt as u64;
+    }
+}
+
+// Non-resizing write implementation
+#[inline]
+fn slice_write(pos_mut: &mut u64, slice: &mut [u8], buf: &[u8]) -> io::Result<usize> {
+    let pos = cmp::min(*pos_mut, slice.len() as u64);
+    #[allow(clippy::cast_possible_truncation)] // pos <= slice.len()
+    let amt = (&mut slice[pos as usize..]).write(buf)?;
+    *pos_mut += amt as u64;
+    Ok(amt)
+}
+
+#[inline]
+fn slice_write_all(pos_mut: &mut u64, slice: &mut [u8], buf: &[u8]) -> io::Result<()> {
+    let n = slice_write(pos_mut, slice, buf)?;
+    if n < buf.len() { Err(io::Error::WRITE_ALL_EOF) } else { Ok(()) }
+}
+
+/// Reserves the required space, and pads the vec with 0s if necessary.
+#[cfg(feature = "alloc")]
+fn reserve_and_pad(pos_mut: &mut u64, vec: &mut Vec<u8>, buf_len: usize) -> io::Result<usize> {
+    let pos = usize::try_from(*pos_mut).map_err(|_| io::Error::CURSOR_POSITION_TOO_LARGE)?;
+
+    // For safety reasons, we don't want these numbers to overflow
+    // otherwise our allocation won't be enough
+    let desired_cap = pos.saturating_add(buf_len);
+    if desired_cap > vec.capacity() {
+        // We want our vec's total capacity
+        // to have room for (pos+buf_len) bytes. Reserve allocates
+        // based on additional elements from the length, so we need to
+        // reserve the difference
+        vec.try_reserve(desired_cap - vec.len())?;
+    }
+    // Pad if pos is above the current len.
+    if pos > vec.len() {
+        vec.resize(pos, 0);
+    }
+    Ok(pos)
+}
+
+/// Resizing write implementation for [`Cursor`].
+///
+/// Cursor is allowed to have a pre-allocated and initialised
+/// vector body, but with a position of 0. This means the [`Write`]
+/// will overwrite the contents of the vec.
+///
+/// This also allows for the vec body to be empty, but with a position of N.
+/// This means that [`Write`] will pad the vec with 0 initially,
+/// before writing anything from that point
+#[cfg(feature = "alloc")]
+fn vec_write(pos_mut: &mut u64, vec: &mut Vec<u8>, buf: &[u8]) -> io::Result<usize> {
+    let buf_len = buf.len();
+    let pos = reserve_and_pad(pos_mut, vec, buf_len)?;
+    let overlap = cmp::min(vec.len() - pos, buf_len);
+    vec[pos..pos + overlap].copy_from_slice(&buf[..overlap]);
+    vec.extend_from_slice(&buf[overlap..]);
+    *pos_mut += buf_len as u64;
+    Ok(buf_len)
+}
+
+impl Write for Cursor<&mut [u8]> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        slice_write(&mut self.pos, self.inner, buf)
+    }
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        slice_write_all(&mut self.pos, self.inner, buf)
+    }
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Write for Cursor<&mut Vec<u8>> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        vec_write(&mut self.pos, self.inner, buf)
+    }
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Write for Cursor<Vec<u8>> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        vec_write(&mut self.pos, &mut self.inner, buf)
+    }
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Write for Cursor<Box<[u8]>> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        slice_write(&mut self.pos, &mut self.inner, buf)
+    }
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        slice_write_all(&mut self.pos, &mut self.inner, buf)
+    }
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<const N: usize> Write for Cursor<[u8; N]> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        slice_write(&mut self.pos, &mut self.inner, buf)
+    }
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        slice_write_all(&mut self.pos, &mut self.inner, buf)
+    }
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "alloc")]
+    use alloc::{string::String, vec, vec::Vec};
+
+    use crate::io::{BufRead as _, Cursor, ErrorKind, Read as _, Seek as _, SeekFrom, Write as _};
+
+    #[test]
+    fn read() {
+        let mut c = Cursor::new(&b"hello world"[..]);
+        let mut buf = [0; 5];
+        c.read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"hello");
+        assert_eq!(c.position(), 5);
+        assert_eq!(c.fill_buf().unwrap(), b" world");
+        c.consume(1);
+        assert_eq!(c.read(&mut buf).unwrap(), 5);
+        assert_eq!(&buf, b"world");
+        assert_eq!(c.read(&mut buf).unwrap(), 0);
+        c.set_position(100);
+        assert_eq!(c.read(&mut buf).unwrap(), 0);
+        assert_eq!(c.fill_buf().unwrap(), b"");
+
+        let mut c = Cursor::new([1, 2, 3]);
+        assert_eq!(c.read_exact(&mut [0; 4]).unwrap_err().kind(), ErrorKind::UnexpectedEof);
+        assert_eq!(c.position(), 3);
+    }
+
+    #[test]
+    fn seek() {
+        let mut c = Cursor::new([0_u8; 10]);
+        assert_eq!(c.seek(SeekFrom::End(-2)).unwrap(), 8);
+        assert_eq!(c.seek(SeekFrom::Current(-3)).unwrap(), 5);
+        assert_eq!(c.seek(SeekFrom::Current(10)).unwrap(), 15);
+        assert_eq!(c.stream_position().unwrap(), 15);
+        assert_eq!(c.seek(SeekFrom::Current(-16)).unwrap_err().kind(), ErrorKind::InvalidInput);
+        assert_eq!(c.position(), 15);
+        c.rewind().unwrap();
+        assert_eq!(c.position(), 0);
+    }
+
+    #[test]
+    fn write_slice() {
+        let mut buf = [0_u8; 4];
+        let mut c = Cursor::new(&mut buf[..]);
+        assert_eq!(c.write(b"ab").unwrap(), 2);
+        c.seek(SeekFrom::Start(1)).unwrap();
+        assert_eq!(c.write(b"xyz").unwrap(), 3);
+        assert_eq!(c.write(b"!").unwrap(), 0);
+        assert_eq!(c.write_all(b"!").unwrap_err().kind(), ErrorKind::WriteZero);
+        assert_eq!(buf, *b"axyz");
+
+        let mut c = Cursor::new([0_u8; 3]);
+        c.set_position(1);
+        c.write_all(b"ab").unwrap();
+        assert_eq!(c.into_inner(), [0, b'a', b'b']);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn write_vec() {
+        let mut c = Cursor::new(vec![1, 2, 3]);
+        c.write_all(b"\x09").unwrap();
+        assert_eq!(c.get_ref(), &[9, 2, 3]);
+        c.set_position(5);
+        c.write_all(&[7, 8]).unwrap();
+        assert_eq!(c.get_ref(), &[9, 2, 3, 0, 0, 7, 8]);
+
+        let mut v = Vec::new();
+        let mut c = Cursor::new(&mut v);
+        write!(c, "{}-{}", 1, 2).unwrap();
+        c.rewind().unwrap();
+        let mut s = String::new();
+        c.read_to_string(&mut s).unwrap();
+        assert_eq!(s, "1-2");
+        assert_eq!(v, b"1-2");
+    }
+}
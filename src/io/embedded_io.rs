@@ -0,0 +1,160 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// Implementations of embedded-io and embedded-io-async traits.
+//
+// The semihosting calls are blocking, so the futures returned by the
+// embedded-io-async implementations are always ready on the first poll.
+
+use crate::io;
+
+impl From<io::ErrorKind> for ::embedded_io::ErrorKind {
+    /// Converts [`io::ErrorKind`] to [`embedded_io::ErrorKind`](::embedded_io::ErrorKind).
+    ///
+    /// Kinds that have no counterpart in embedded-io are mapped to `Other`.
+    fn from(kind: io::ErrorKind) -> Self {
+        use ::embedded_io::ErrorKind as E;
+        match kind {
+            io::ErrorKind::NotFound => E::NotFound,
+            io::ErrorKind::PermissionDenied => E::PermissionDenied,
+            io::ErrorKind::ConnectionRefused => E::ConnectionRefused,
+            io::ErrorKind::ConnectionReset => E::ConnectionReset,
+            io::ErrorKind::ConnectionAborted => E::ConnectionAborted,
+            io::ErrorKind::NotConnected => E::NotConnected,
+            io::ErrorKind::AddrInUse => E::AddrInUse,
+            io::ErrorKind::AddrNotAvailable => E::AddrNotAvailable,
+            io::ErrorKind::BrokenPipe => E::BrokenPipe,
+            io::ErrorKind::AlreadyExists => E::AlreadyExists,
+            io::ErrorKind::InvalidInput => E::InvalidInput,
+            io::ErrorKind::InvalidData => E::InvalidData,
+            io::ErrorKind::TimedOut => E::TimedOut,
+            io::ErrorKind::Interrupted => E::Interrupted,
+            io::ErrorKind::Unsupported => E::Unsupported,
+            io::ErrorKind::OutOfMemory => E::OutOfMemory,
+            io::ErrorKind::WriteZero => E::WriteZero,
+            _ => E::Other,
+        }
+    }
+}
+
+impl ::embedded_io::Error for io::Error {
+    #[inline]
+    fn kind(&self) -> ::embedded_io::ErrorKind {
+        self.kind().into()
+    }
+}
+
+impl From<::embedded_io::SeekFrom> for io::SeekFrom {
+    #[inline]
+    fn from(pos: ::embedded_io::SeekFrom) -> Self {
+        match pos {
+            ::embedded_io::SeekFrom::Start(n) => Self::Start(n),
+            ::embedded_io::SeekFrom::End(n) => Self::End(n),
+            ::embedded_io::SeekFrom::Current(n) => Self::Current(n),
+        }
+    }
+}
+impl From<io::SeekFrom> for ::embedded_io::SeekFrom {
+    #[inline]
+    fn from(pos: io::SeekFrom) -> Self {
+        match pos {
+            io::SeekFrom::Start(n) => Self::Start(n),
+            io::SeekFrom::End(n) => Self::End(n),
+            io::SeekFrom::Current(n) => Self::Current(n),
+        }
+    }
+}
+
+#[cfg(any(feature = "fs", feature = "stdio"))]
+macro_rules! impl_error_type {
+    ($($ty:ty),* $(,)?) => {$(
+        impl ::embedded_io::ErrorType for $ty {
+            type Error = io::Error;
+        }
+    )*};
+}
+#[cfg(any(feature = "fs", feature = "stdio"))]
+macro_rules! impl_read {
+    ($($ty:ty),* $(,)?) => {$(
+        impl ::embedded_io::Read for $ty {
+            #[inline]
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                io::Read::read(self, buf)
+            }
+        }
+        #[cfg(feature = "embedded-io-async")]
+        impl ::embedded_io_async::Read for $ty {
+            #[inline]
+            async fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                io::Read::read(self, buf)
+            }
+        }
+    )*};
+}
+#[cfg(any(feature = "fs", feature = "stdio"))]
+macro_rules! impl_write {
+    ($($ty:ty),* $(,)?) => {$(
+        impl ::embedded_io::Write for $ty {
+            #[inline]
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                io::Write::write(self, buf)
+            }
+            #[inline]
+            fn flush(&mut self) -> io::Result<()> {
+                io::Write::flush(self)
+            }
+        }
+        #[cfg(feature = "embedded-io-async")]
+        impl ::embedded_io_async::Write for $ty {
+            #[inline]
+            async fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                io::Write::write(self, buf)
+            }
+            #[inline]
+            async fn flush(&mut self) -> io::Result<()> {
+                io::Write::flush(self)
+            }
+        }
+    )*};
+}
+#[cfg(feature = "fs")]
+macro_rules! impl_seek {
+    ($($ty:ty),* $(,)?) => {$(
+        impl ::embedded_io::Seek for $ty {
+            #[inline]
+            fn seek(&mut self, pos: ::embedded_io::SeekFrom) -> io::Result<u64> {
+                io::Seek::seek(self, pos.into())
+            }
+            #[inline]
+            fn stream_position(&mut self) -> io::Result<u64> {
+                io::Seek::stream_position(self)
+            }
+        }
+        #[cfg(feature = "embedded-io-async")]
+        impl ::embedded_io_async::Seek for $ty {
+            #[inline]
+            async fn seek(&mut self, pos: ::embedded_io::SeekFrom) -> io::Result<u64> {
+                io::Seek::seek(self, pos.into())
+            }
+            #[inline]
+            async fn stream_position(&mut self) -> io::Result<u64> {
+                io::Seek::stream_position(self)
+            }
+        }
+    )*};
+}
+
+#[cfg(feature = "fs")]
+impl_error_type!(crate::fs::File);
+#[cfg(feature = "fs")]
+impl_read!(crate::fs::File);
+#[cfg(feature = "fs")]
+impl_write!(crate::fs::File);
+#[cfg(feature = "fs")]
+impl_seek!(crate::fs::File);
+
+#[cfg(feature = "stdio")]
+impl_error_type!(io::Stdin, io::Stdout, io::Stderr);
+#[cfg(feature = "stdio")]
+impl_read!(io::Stdin);
+#[cfg(feature = "stdio")]
+impl_write!(io::Stdout, io::Stderr);
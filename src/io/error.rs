@@ -1,5 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+#[cfg(all(feature = "alloc", not(semihosting_no_error_in_core)))]
+use alloc::boxed::Box;
 use core::fmt;
 
 use crate::sys;
@@ -38,12 +40,40 @@ macro_rules! const_error {
 
 #[allow(dead_code)]
 impl Error {
+    pub(crate) const COPY_SOURCE_NOT_FILE: Self = const_error!(
+        ErrorKind::InvalidInput,
+        "the source path is neither a regular file nor a symlink to a regular file",
+    );
+
+    pub(crate) const CURSOR_POSITION_TOO_LARGE: Self = const_error!(
+        ErrorKind::InvalidInput,
+        "cursor position exceeds maximum possible vector length",
+    );
+
+    pub(crate) const FILE_LARGER_THAN_BUFFER: Self =
+        const_error!(ErrorKind::FileTooLarge, "file is larger than the provided buffer");
+
+    pub(crate) const INTERIOR_NUL_IN_PATH: Self =
+        const_error!(ErrorKind::InvalidFilename, "path contains an interior nul byte");
+
     pub(crate) const INVALID_UTF8: Self =
         const_error!(ErrorKind::InvalidData, "stream did not contain valid UTF-8");
 
+    pub(crate) const INVALID_TIMESTAMP: Self =
+        const_error!(ErrorKind::InvalidData, "invalid timestamp");
+
+    pub(crate) const LINE_TOO_LONG: Self =
+        const_error!(ErrorKind::InvalidData, "line does not fit in the buffer");
+
+    pub(crate) const PATH_TOO_LONG: Self =
+        const_error!(ErrorKind::InvalidFilename, "path does not fit in the buffer");
+
     pub(crate) const READ_EXACT_EOF: Self =
         const_error!(ErrorKind::UnexpectedEof, "failed to fill whole buffer");
 
+    pub(crate) const SEEK_NEGATIVE_OR_OVERFLOW: Self =
+        const_error!(ErrorKind::InvalidInput, "invalid seek to a negative or overflowing position");
+
     pub(crate) const UNKNOWN_THREAD_COUNT: Self = const_error!(
         ErrorKind::NotFound,
         "The number of hardware threads is not known for the target platform",
@@ -58,7 +88,6 @@ impl Error {
     pub(crate) const ZERO_TIMEOUT: Self =
         const_error!(ErrorKind::InvalidInput, "cannot set a 0 duration timeout");
 
-    #[cfg(feature = "fs")]
     pub(crate) const EINVAL: Self = Self { repr: Repr::Os(sys::EINVAL) };
 }
 
@@ -86,6 +115,8 @@ enum Repr {
     Os(RawOsError),
     Simple(ErrorKind),
     SimpleMessage(&'static SimpleMessage),
+    #[cfg(all(feature = "alloc", not(semihosting_no_error_in_core)))]
+    Custom(Box<Custom>),
 }
 
 /// The type of raw OS error codes returned by [`Error::raw_os_error`].
@@ -100,6 +131,13 @@ pub(crate) struct SimpleMessage {
     pub(crate) message: &'static str,
 }
 
+#[cfg(all(feature = "alloc", not(semihosting_no_error_in_core)))]
+#[derive(Debug)]
+struct Custom {
+    kind: ErrorKind,
+    error: Box<dyn core::error::Error + Send + Sync>,
+}
+
 /// A list specifying general categories of I/O error.
 ///
 /// See [`std::io::ErrorKind` documentation][std] for details.
@@ -233,7 +271,41 @@ impl Error {
         Self { repr: Repr::SimpleMessage(msg) }
     }
 
-    // TODO(io,alloc): provide new,other when alloc feature is enabled?
+    /// Creates a new I/O error from a known kind of error as well as an
+    /// arbitrary error payload.
+    ///
+    /// See [`std::io::Error::new` documentation][std] for details.
+    ///
+    /// [std]: https://doc.rust-lang.org/std/io/struct.Error.html#method.new
+    #[cfg(all(feature = "alloc", not(semihosting_no_error_in_core)))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn new<E>(kind: ErrorKind, error: E) -> Self
+    where
+        E: Into<Box<dyn core::error::Error + Send + Sync>>,
+    {
+        Self::_new(kind, error.into())
+    }
+
+    /// Creates a new I/O error from an arbitrary error payload.
+    ///
+    /// This is a shortcut for [`Error::new`] with [`ErrorKind::Other`].
+    ///
+    /// See [`std::io::Error::other` documentation][std] for details.
+    ///
+    /// [std]: https://doc.rust-lang.org/std/io/struct.Error.html#method.other
+    #[cfg(all(feature = "alloc", not(semihosting_no_error_in_core)))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn other<E>(error: E) -> Self
+    where
+        E: Into<Box<dyn core::error::Error + Send + Sync>>,
+    {
+        Self::_new(ErrorKind::Other, error.into())
+    }
+
+    #[cfg(all(feature = "alloc", not(semihosting_no_error_in_core)))]
+    fn _new(kind: ErrorKind, error: Box<dyn core::error::Error + Send + Sync>) -> Self {
+        Self { repr: Repr::Custom(Box::new(Custom { kind, error })) }
+    }
 
     // TODO(io): last_os_error: Arm semihosting has sys_errno, but MIPS UHI doesn't.
 
@@ -250,18 +322,78 @@ impl Error {
     pub fn raw_os_error(&self) -> Option<RawOsError> {
         match self.repr {
             Repr::Os(code) => Some(code),
-            // Repr::Custom(..) |
+            #[cfg(all(feature = "alloc", not(semihosting_no_error_in_core)))]
+            Repr::Custom(..) => None,
             Repr::Simple(..) | Repr::SimpleMessage(..) => None,
         }
     }
 
+    /// Returns a reference to the inner error wrapped by this error (if any).
+    ///
+    /// If this [`Error`] was constructed via [`new`](Error::new) or [`other`](Error::other),
+    /// then this function will return [`Some`], otherwise it will return [`None`].
+    ///
+    /// See [`std::io::Error::get_ref` documentation][std] for details.
+    ///
+    /// [std]: https://doc.rust-lang.org/std/io/struct.Error.html#method.get_ref
+    #[cfg(all(feature = "alloc", not(semihosting_no_error_in_core)))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    #[must_use]
+    pub fn get_ref(&self) -> Option<&(dyn core::error::Error + Send + Sync + 'static)> {
+        match &self.repr {
+            Repr::Custom(c) => Some(&*c.error),
+            Repr::Os(..) | Repr::Simple(..) | Repr::SimpleMessage(..) => None,
+        }
+    }
+
+    /// Returns a mutable reference to the inner error wrapped by this error
+    /// (if any).
+    ///
+    /// If this [`Error`] was constructed via [`new`](Error::new) or [`other`](Error::other),
+    /// then this function will return [`Some`], otherwise it will return [`None`].
+    ///
+    /// See [`std::io::Error::get_mut` documentation][std] for details.
+    ///
+    /// [std]: https://doc.rust-lang.org/std/io/struct.Error.html#method.get_mut
+    #[cfg(all(feature = "alloc", not(semihosting_no_error_in_core)))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    #[must_use]
+    pub fn get_mut(&mut self) -> Option<&mut (dyn core::error::Error + Send + Sync + 'static)> {
+        match &mut self.repr {
+            Repr::Custom(c) => Some(&mut *c.error),
+            Repr::Os(..) | Repr::Simple(..) | Repr::SimpleMessage(..) => None,
+        }
+    }
+
+    /// Consumes the `Error`, returning its inner error (if any).
+    ///
+    /// If this [`Error`] was constructed via [`new`](Error::new) or [`other`](Error::other),
+    /// then this function will return [`Some`], otherwise it will return [`None`].
+    ///
+    /// See [`std::io::Error::into_inner` documentation][std] for details.
+    ///
+    /// [std]: https://doc.rust-lang.org/std/io/struct.Error.html#method.into_inner
+    #[cfg(all(feature = "alloc", not(semihosting_no_error_in_core)))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    #[must_use = "`self` will be dropped if the result is not used"]
+    pub fn into_inner(self) -> Option<Box<dyn core::error::Error + Send + Sync>> {
+        match self.repr {
+            Repr::Custom(c) => Some(c.error),
+            Repr::Os(..) | Repr::Simple(..) | Repr::SimpleMessage(..) => None,
+        }
+    }
+
     /// Returns the corresponding [`ErrorKind`] for this error.
     #[inline]
     #[must_use]
     pub fn kind(&self) -> ErrorKind {
         match self.repr {
             Repr::Os(code) => sys::decode_error_kind(code),
-            // Repr::Custom(ref c) => c.kind,
+            #[cfg(all(feature = "alloc", not(semihosting_no_error_in_core)))]
+            Repr::Custom(ref c) => c.kind,
             Repr::Simple(kind) => kind,
             Repr::SimpleMessage(msg) => msg.kind,
         }
@@ -271,7 +403,8 @@ impl Error {
     pub(crate) fn is_interrupted(&self) -> bool {
         match self.repr {
             Repr::Os(code) => sys::is_interrupted(code),
-            // Repr::Custom(ref c) => c.kind == ErrorKind::Interrupted,
+            #[cfg(all(feature = "alloc", not(semihosting_no_error_in_core)))]
+            Repr::Custom(ref c) => c.kind == ErrorKind::Interrupted,
             Repr::Simple(kind) => kind == ErrorKind::Interrupted,
             Repr::SimpleMessage(m) => m.kind == ErrorKind::Interrupted,
         }
@@ -285,10 +418,10 @@ impl fmt::Debug for Repr {
                 .debug_struct("Os")
                 .field("code", &code)
                 .field("kind", &sys::decode_error_kind(*code))
-                // TODO(io)
-                // .field("message", &sys::os::error_string(code))
+                .field("message", &sys::error_string(*code))
                 .finish(),
-            // Self::Custom(c) => fmt::Debug::fmt(&c, fmt),
+            #[cfg(all(feature = "alloc", not(semihosting_no_error_in_core)))]
+            Self::Custom(c) => fmt::Debug::fmt(&c, f),
             Self::Simple(kind) => f.debug_tuple("Kind").field(&kind).finish(),
             Self::SimpleMessage(msg) => f
                 .debug_struct("Error")
@@ -303,13 +436,11 @@ impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self.repr {
             Repr::Os(code) => {
-                // TODO(io)
-                // let detail = sys::os::error_string(code);
-                // write!(f, "{detail} (os error {code})")
-                let detail = sys::decode_error_kind(code);
+                let detail = sys::error_string(code);
                 write!(f, "{detail} (os error {code})")
             }
-            // Repr::Custom(ref c) => c.error.fmt(fmt),
+            #[cfg(all(feature = "alloc", not(semihosting_no_error_in_core)))]
+            Repr::Custom(ref c) => c.error.fmt(f),
             Repr::Simple(kind) => f.write_str(kind.as_str()),
             Repr::SimpleMessage(msg) => msg.message.fmt(f),
         }
@@ -321,7 +452,62 @@ impl core::error::Error for Error {
     fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         match self.repr {
             Repr::Os(..) | Repr::Simple(..) | Repr::SimpleMessage(..) => None,
-            // Repr::Custom(c) => c.error.source(),
+            #[cfg(all(feature = "alloc", not(semihosting_no_error_in_core)))]
+            Repr::Custom(ref c) => c.error.source(),
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "alloc")]
+mod tests {
+    use alloc::{format, string::ToString as _};
+
+    use super::{Error, ErrorKind};
+
+    #[test]
+    fn os() {
+        let e = Error::from_raw_os_error(2);
+        assert_eq!(e.raw_os_error(), Some(2));
+        assert_eq!(e.kind(), ErrorKind::NotFound);
+        assert_eq!(e.to_string(), "No such file or directory (os error 2)");
+        assert!(format!("{e:?}").contains("No such file or directory"));
+        let e = Error::from_raw_os_error(9999);
+        assert_eq!(e.kind(), ErrorKind::Other);
+        assert_eq!(e.to_string(), "Unknown error (os error 9999)");
+    }
+
+    #[test]
+    fn custom() {
+        use alloc::boxed::Box;
+        use core::fmt;
+
+        #[derive(Debug)]
+        struct MyError;
+        impl fmt::Display for MyError {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("my error")
+            }
         }
+        impl core::error::Error for MyError {}
+
+        let mut e = Error::new(ErrorKind::InvalidData, MyError);
+        assert_eq!(e.kind(), ErrorKind::InvalidData);
+        assert_eq!(e.raw_os_error(), None);
+        assert_eq!(e.to_string(), "my error");
+        assert!(e.get_ref().unwrap().is::<MyError>());
+        assert!(e.get_mut().unwrap().is::<MyError>());
+        let inner: Box<dyn core::error::Error + Send + Sync> = e.into_inner().unwrap();
+        assert!(inner.downcast::<MyError>().is_ok());
+
+        let e = Error::other("oh no");
+        assert_eq!(e.kind(), ErrorKind::Other);
+        assert_eq!(e.to_string(), "oh no");
+        assert!(!e.is_interrupted());
+        assert!(Error::new(ErrorKind::Interrupted, "x").is_interrupted());
+
+        let e = Error::from(ErrorKind::NotFound);
+        assert!(e.get_ref().is_none());
+        assert!(e.into_inner().is_none());
     }
 }
@@ -1,8 +1,10 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
 use core::{cmp, fmt, mem};
 
-use crate::io::{self, Read, Seek, SeekFrom, Write};
+use crate::io::{self, BufRead, Read, Seek, SeekFrom, Write};
 
 // -----------------------------------------------------------------------------
 // Forwarding implementations
@@ -24,14 +26,16 @@ impl<R: ?Sized + Read> Read for &mut R {
     // fn is_read_vectored(&self) -> bool {
     //     (**self).is_read_vectored()
     // }
-    // #[inline]
-    // fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
-    //     (**self).read_to_end(buf)
-    // }
-    // #[inline]
-    // fn read_to_string(&mut self, buf: &mut String) -> io::Result<usize> {
-    //     (**self).read_to_string(buf)
-    // }
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
+        (**self).read_to_end(buf)
+    }
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_to_string(&mut self, buf: &mut String) -> io::Result<usize> {
+        (**self).read_to_string(buf)
+    }
     #[inline]
     fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
         (**self).read_exact(buf)
@@ -41,6 +45,34 @@ impl<R: ?Sized + Read> Read for &mut R {
     //     (**self).read_buf_exact(cursor)
     // }
 }
+impl<B: ?Sized + BufRead> BufRead for &mut B {
+    #[inline]
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        (**self).fill_buf()
+    }
+    #[inline]
+    fn consume(&mut self, amt: usize) {
+        (**self).consume(amt);
+    }
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_until(&mut self, byte: u8, buf: &mut Vec<u8>) -> io::Result<usize> {
+        (**self).read_until(byte, buf)
+    }
+    #[inline]
+    fn skip_until(&mut self, byte: u8) -> io::Result<usize> {
+        (**self).skip_until(byte)
+    }
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_line(&mut self, buf: &mut String) -> io::Result<usize> {
+        (**self).read_line(buf)
+    }
+    #[inline]
+    fn read_line_into<'a>(&mut self, buf: &'a mut [u8]) -> io::Result<&'a str> {
+        (**self).read_line_into(buf)
+    }
+}
 impl<W: ?Sized + Write> Write for &mut W {
     #[inline]
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
@@ -84,14 +116,14 @@ impl<S: ?Sized + Seek> Seek for &mut S {
     // fn stream_len(&mut self) -> io::Result<u64> {
     //     (**self).stream_len()
     // }
-    // #[inline]
-    // fn stream_position(&mut self) -> io::Result<u64> {
-    //     (**self).stream_position()
-    // }
-    // #[inline]
-    // fn seek_relative(&mut self, offset: i64) -> io::Result<()> {
-    //     (**self).seek_relative(offset)
-    // }
+    #[inline]
+    fn stream_position(&mut self) -> io::Result<u64> {
+        (**self).stream_position()
+    }
+    #[inline]
+    fn seek_relative(&mut self, offset: i64) -> io::Result<()> {
+        (**self).seek_relative(offset)
+    }
 }
 
 #[cfg(feature = "alloc")]
@@ -112,14 +144,14 @@ impl<R: ?Sized + Read> Read for alloc::boxed::Box<R> {
     // fn is_read_vectored(&self) -> bool {
     //     (**self).is_read_vectored()
     // }
-    // #[inline]
-    // fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
-    //     (**self).read_to_end(buf)
-    // }
-    // #[inline]
-    // fn read_to_string(&mut self, buf: &mut String) -> io::Result<usize> {
-    //     (**self).read_to_string(buf)
-    // }
+    #[inline]
+    fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
+        (**self).read_to_end(buf)
+    }
+    #[inline]
+    fn read_to_string(&mut self, buf: &mut String) -> io::Result<usize> {
+        (**self).read_to_string(buf)
+    }
     #[inline]
     fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
         (**self).read_exact(buf)
@@ -130,6 +162,33 @@ impl<R: ?Sized + Read> Read for alloc::boxed::Box<R> {
     // }
 }
 #[cfg(feature = "alloc")]
+impl<B: ?Sized + BufRead> BufRead for alloc::boxed::Box<B> {
+    #[inline]
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        (**self).fill_buf()
+    }
+    #[inline]
+    fn consume(&mut self, amt: usize) {
+        (**self).consume(amt);
+    }
+    #[inline]
+    fn read_until(&mut self, byte: u8, buf: &mut Vec<u8>) -> io::Result<usize> {
+        (**self).read_until(byte, buf)
+    }
+    #[inline]
+    fn skip_until(&mut self, byte: u8) -> io::Result<usize> {
+        (**self).skip_until(byte)
+    }
+    #[inline]
+    fn read_line(&mut self, buf: &mut String) -> io::Result<usize> {
+        (**self).read_line(buf)
+    }
+    #[inline]
+    fn read_line_into<'a>(&mut self, buf: &'a mut [u8]) -> io::Result<&'a str> {
+        (**self).read_line_into(buf)
+    }
+}
+#[cfg(feature = "alloc")]
 impl<W: ?Sized + Write> Write for alloc::boxed::Box<W> {
     #[inline]
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
@@ -174,14 +233,14 @@ impl<S: ?Sized + Seek> Seek for alloc::boxed::Box<S> {
     // fn stream_len(&mut self) -> io::Result<u64> {
     //     (**self).stream_len()
     // }
-    // #[inline]
-    // fn stream_position(&mut self) -> io::Result<u64> {
-    //     (**self).stream_position()
-    // }
-    // #[inline]
-    // fn seek_relative(&mut self, offset: i64) -> io::Result<()> {
-    //     (**self).seek_relative(offset)
-    // }
+    #[inline]
+    fn stream_position(&mut self) -> io::Result<u64> {
+        (**self).stream_position()
+    }
+    #[inline]
+    fn seek_relative(&mut self, offset: i64) -> io::Result<()> {
+        (**self).seek_relative(offset)
+    }
 }
 
 // -----------------------------------------------------------------------------
@@ -272,23 +331,36 @@ impl Read for &[u8] {
     //     *self = b;
     //     Ok(())
     // }
-    // #[inline]
-    // fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
-    //     let len = self.len();
-    //     buf.try_reserve(len)?;
-    //     buf.extend_from_slice(*self);
-    //     *self = &self[len..];
-    //     Ok(len)
-    // }
-    // #[inline]
-    // fn read_to_string(&mut self, buf: &mut String) -> io::Result<usize> {
-    //     let content = str::from_utf8(self).map_err(|_| io::Error::INVALID_UTF8)?;
-    //     let len = self.len();
-    //     buf.try_reserve(len)?;
-    //     buf.push_str(content);
-    //     *self = &self[len..];
-    //     Ok(len)
-    // }
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
+        let len = self.len();
+        buf.try_reserve(len)?;
+        buf.extend_from_slice(self);
+        *self = &self[len..];
+        Ok(len)
+    }
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_to_string(&mut self, buf: &mut String) -> io::Result<usize> {
+        let content = core::str::from_utf8(self).map_err(|_| io::Error::INVALID_UTF8)?;
+        let len = self.len();
+        buf.try_reserve(len)?;
+        buf.push_str(content);
+        *self = &self[len..];
+        Ok(len)
+    }
+}
+
+impl BufRead for &[u8] {
+    #[inline]
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        Ok(*self)
+    }
+    #[inline]
+    fn consume(&mut self, amt: usize) {
+        *self = &self[amt..];
+    }
 }
 
 /// Write is implemented for `&mut [u8]` by copying into the slice, overwriting
@@ -433,23 +505,39 @@ impl Read for alloc::collections::VecDeque<u8> {
     //     self.drain(..len);
     //     Ok(())
     // }
-    // #[inline]
-    // fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
-    //     // The total len is known upfront so we can reserve it in a single call.
-    //     let len = self.len();
-    //     buf.try_reserve(len)?;
-    //
-    //     let (front, back) = self.as_slices();
-    //     buf.extend_from_slice(front);
-    //     buf.extend_from_slice(back);
-    //     self.clear();
-    //     Ok(len)
-    // }
-    // #[inline]
-    // fn read_to_string(&mut self, buf: &mut String) -> io::Result<usize> {
-    //     // SAFETY: We only append to the buffer
-    //     unsafe { io::append_to_string(buf, |buf| self.read_to_end(buf)) }
-    // }
+    #[inline]
+    fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
+        // The total len is known upfront so we can reserve it in a single call.
+        let len = self.len();
+        buf.try_reserve(len)?;
+
+        let (front, back) = self.as_slices();
+        buf.extend_from_slice(front);
+        buf.extend_from_slice(back);
+        self.clear();
+        Ok(len)
+    }
+    #[inline]
+    fn read_to_string(&mut self, buf: &mut String) -> io::Result<usize> {
+        // SAFETY: We only append to the buffer
+        unsafe { io::append_to_string(buf, |buf| self.read_to_end(buf)) }
+    }
+}
+/// BufRead is implemented for `VecDeque<u8>` by reading bytes from the front of the `VecDeque`.
+#[cfg(feature = "alloc")]
+impl BufRead for alloc::collections::VecDeque<u8> {
+    /// Returns the contents of the "front" slice as returned by
+    /// [`as_slices`][`alloc::collections::VecDeque::as_slices`]. If the contained byte slices of the `VecDeque` are
+    /// discontiguous, multiple calls to `fill_buf` will be needed to read the entire content.
+    #[inline]
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        let (front, _) = self.as_slices();
+        Ok(front)
+    }
+    #[inline]
+    fn consume(&mut self, amt: usize) {
+        self.drain(..amt);
+    }
 }
 // <[_]>::split_at_checked requires Rust 1.80.
 #[cfg(feature = "alloc")]
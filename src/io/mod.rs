@@ -9,20 +9,44 @@
 
 // Based on nightly-2025-02-19's std::io module.
 
-// TODO(io): io utilities e.g., Cursor?
-
 pub use self::error::{Error, ErrorKind, RawOsError, Result};
 #[macro_use]
 mod error;
 
+pub use self::buffered::{BufReader, BufWriter, IntoInnerError};
+#[cfg(feature = "alloc")]
+pub use self::buffered::{HeapBufReader, HeapBufWriter};
+mod buffered;
+
+pub use self::copy::copy;
+#[cfg(feature = "fs")]
+pub(crate) use self::copy::file_copy;
+mod copy;
+
+pub use self::cursor::Cursor;
+mod cursor;
+
+#[cfg(feature = "embedded-io")]
+mod embedded_io;
+#[cfg(all(feature = "ufmt", any(feature = "fs", feature = "stdio")))]
+mod ufmt;
+
 mod impls;
 
+pub use self::util::{Empty, Repeat, Sink, empty, repeat, sink};
+mod util;
+
 #[cfg(feature = "stdio")]
-pub use self::stdio::{IsTerminal, Stderr, Stdin, Stdout, stderr, stdin, stdout};
+pub use self::stdio::{IsTerminal, Stderr, Stdin, Stdout, StdoutLock, stderr, stdin, stdout};
 #[cfg(feature = "stdio")]
 mod stdio;
 
-use core::fmt;
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+use core::{cmp, fmt, str};
+
+#[cfg(feature = "alloc")]
+use self::buffered::DEFAULT_BUF_SIZE;
 
 const _: fn() = || {
     fn assert_dyn_compatibility<T: ?Sized>() {}
@@ -61,26 +85,27 @@ pub trait Read {
     /// [std]: https://doc.rust-lang.org/std/io/trait.Read.html#tymethod.read
     fn read(&mut self, buf: &mut [u8]) -> Result<usize>;
 
-    // TODO(io,alloc)
-    // /// Read all bytes until EOF in this source, placing them into `buf`.
-    // ///
-    // /// See [`std::io::Read::read_to_end` documentation][std] for details.
-    // ///
-    // /// [std]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_to_end
-    // #[cfg(feature = "alloc")]
-    // fn read_to_end(&mut self, buf: &mut Vec<u8>) -> Result<usize> {
-    //     default_read_to_end(self, buf)
-    // }
-    //
-    // /// Read all bytes until EOF in this source, appending them to `buf`.
-    // ///
-    // /// See [`std::io::Read::read_to_string` documentation][std] for details.
-    // ///
-    // /// [std]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_to_string
-    // #[cfg(feature = "alloc")]
-    // fn read_to_string(&mut self, buf: &mut String) -> Result<usize> {
-    //     default_read_to_string(self, buf)
-    // }
+    /// Read all bytes until EOF in this source, placing them into `buf`.
+    ///
+    /// See [`std::io::Read::read_to_end` documentation][std] for details.
+    ///
+    /// [std]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_to_end
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn read_to_end(&mut self, buf: &mut Vec<u8>) -> Result<usize> {
+        default_read_to_end(self, buf, None)
+    }
+
+    /// Read all bytes until EOF in this source, appending them to `buf`.
+    ///
+    /// See [`std::io::Read::read_to_string` documentation][std] for details.
+    ///
+    /// [std]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_to_string
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn read_to_string(&mut self, buf: &mut String) -> Result<usize> {
+        default_read_to_string(self, buf, None)
+    }
 
     /// Read the exact number of bytes required to fill `buf`.
     ///
@@ -90,6 +115,429 @@ pub trait Read {
     fn read_exact(&mut self, buf: &mut [u8]) -> Result<()> {
         default_read_exact(self, buf)
     }
+
+    /// Transforms this `Read` instance to an [`Iterator`] over its bytes.
+    ///
+    /// See [`std::io::Read::bytes` documentation][std] for details.
+    ///
+    /// [std]: https://doc.rust-lang.org/std/io/trait.Read.html#method.bytes
+    fn bytes(self) -> Bytes<Self>
+    where
+        Self: Sized,
+    {
+        Bytes { inner: self }
+    }
+
+    /// Creates an adapter which will chain this stream with another.
+    ///
+    /// See [`std::io::Read::chain` documentation][std] for details.
+    ///
+    /// [std]: https://doc.rust-lang.org/std/io/trait.Read.html#method.chain
+    fn chain<R: Read>(self, next: R) -> Chain<Self, R>
+    where
+        Self: Sized,
+    {
+        Chain { first: self, second: next, done_first: false }
+    }
+
+    /// Creates an adapter which will read at most `limit` bytes from it.
+    ///
+    /// See [`std::io::Read::take` documentation][std] for details.
+    ///
+    /// [std]: https://doc.rust-lang.org/std/io/trait.Read.html#method.take
+    fn take(self, limit: u64) -> Take<Self>
+    where
+        Self: Sized,
+    {
+        Take { inner: self, limit }
+    }
+}
+
+/// The `no_std` subset of `std::io::BufRead`.
+///
+/// Unless explicitly stated otherwise, API contracts adhere to `std::io::BufRead`.
+///
+/// See [`std::io::BufRead` documentation][std] for details.
+///
+/// [std]: https://doc.rust-lang.org/std/io/trait.BufRead.html
+pub trait BufRead: Read {
+    /// Returns the contents of the internal buffer, filling it with more data
+    /// from the inner reader if it is empty.
+    ///
+    /// See [`std::io::BufRead::fill_buf` documentation][std] for details.
+    ///
+    /// [std]: https://doc.rust-lang.org/std/io/trait.BufRead.html#tymethod.fill_buf
+    fn fill_buf(&mut self) -> Result<&[u8]>;
+
+    /// Tells this buffer that `amt` bytes have been consumed from the buffer,
+    /// so they should no longer be returned in calls to `read`.
+    ///
+    /// See [`std::io::BufRead::consume` documentation][std] for details.
+    ///
+    /// [std]: https://doc.rust-lang.org/std/io/trait.BufRead.html#tymethod.consume
+    fn consume(&mut self, amt: usize);
+
+    /// Reads all bytes into `buf` until the delimiter `byte` or EOF is reached.
+    ///
+    /// See [`std::io::BufRead::read_until` documentation][std] for details.
+    ///
+    /// [std]: https://doc.rust-lang.org/std/io/trait.BufRead.html#method.read_until
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn read_until(&mut self, byte: u8, buf: &mut Vec<u8>) -> Result<usize> {
+        read_until(self, byte, buf)
+    }
+
+    /// Skips all bytes until the delimiter `byte` or EOF is reached.
+    ///
+    /// See [`std::io::BufRead::skip_until` documentation][std] for details.
+    ///
+    /// [std]: https://doc.rust-lang.org/std/io/trait.BufRead.html#method.skip_until
+    fn skip_until(&mut self, byte: u8) -> Result<usize> {
+        skip_until(self, byte)
+    }
+
+    /// Reads all bytes until a newline (the `0xA` byte) is reached, and append
+    /// them to the provided `String` buffer.
+    ///
+    /// See [`std::io::BufRead::read_line` documentation][std] for details.
+    ///
+    /// [std]: https://doc.rust-lang.org/std/io/trait.BufRead.html#method.read_line
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn read_line(&mut self, buf: &mut String) -> Result<usize> {
+        // Note that we are not calling the `.read_until` method here, but
+        // rather our hardcoded implementation, since `append_to_string` relies
+        // on the bytes being only appended to the buffer.
+        // SAFETY: `read_until` only appends to the buffer.
+        unsafe { append_to_string(buf, |b| read_until(self, b'\n', b)) }
+    }
+
+    /// Reads all bytes until a newline (the `0xA` byte) is reached into the
+    /// provided buffer, and returns the line.
+    ///
+    /// This is a variant of [`read_line`](BufRead::read_line) that does not
+    /// require `alloc`. Like `read_line`, the returned line includes the
+    /// newline if one was found, and is empty if EOF has been reached.
+    ///
+    /// # Errors
+    ///
+    /// If the line (including the newline) does not fit in `buf`, an error of
+    /// kind [`ErrorKind::InvalidData`] is returned. In this case, the bytes
+    /// that have been read into `buf` are consumed from this reader.
+    ///
+    /// This function has the same error semantics as [`read_line`](BufRead::read_line)
+    /// for the other errors.
+    fn read_line_into<'a>(&mut self, buf: &'a mut [u8]) -> Result<&'a str> {
+        read_line_into(self, buf)
+    }
+
+    /// Returns an iterator over the contents of this reader split on the byte
+    /// `byte`.
+    ///
+    /// See [`std::io::BufRead::split` documentation][std] for details.
+    ///
+    /// [std]: https://doc.rust-lang.org/std/io/trait.BufRead.html#method.split
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn split(self, byte: u8) -> Split<Self>
+    where
+        Self: Sized,
+    {
+        Split { buf: self, delim: byte }
+    }
+
+    /// Returns an iterator over the lines of this reader.
+    ///
+    /// See [`std::io::BufRead::lines` documentation][std] for details.
+    ///
+    /// [std]: https://doc.rust-lang.org/std/io/trait.BufRead.html#method.lines
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn lines(self) -> Lines<Self>
+    where
+        Self: Sized,
+    {
+        Lines { buf: self }
+    }
+}
+
+// Several `read_to_string` and `read_line` methods in the standard library will
+// append data into a `String` buffer, but we need to be pretty careful when
+// doing this. The implementation will just call `.as_mut_vec()` and then
+// delegate to a byte-oriented reading method, but we must ensure that when
+// returning we never leave `buf` in a state such that it contains invalid UTF-8
+// in its bounds.
+//
+// To this end, we use an RAII guard (to protect against panics) which updates
+// the length of the string when it is dropped. This guard initially truncates
+// the string to the prior length and only after we've validated that the
+// new contents are valid UTF-8 do we allow it to set a longer length.
+//
+// # Safety
+//
+// The caller must ensure that `f` only appends data to the given vector.
+#[cfg(feature = "alloc")]
+pub(crate) unsafe fn append_to_string<F>(buf: &mut String, f: F) -> Result<usize>
+where
+    F: FnOnce(&mut Vec<u8>) -> Result<usize>,
+{
+    struct Guard<'a> {
+        buf: &'a mut Vec<u8>,
+        len: usize,
+    }
+    impl Drop for Guard<'_> {
+        fn drop(&mut self) {
+            // SAFETY: `len` is the length of the valid UTF-8 prefix, which is
+            // not greater than the length of the vector.
+            unsafe { self.buf.set_len(self.len) }
+        }
+    }
+
+    // SAFETY: the guard ensures that `buf` contains only valid UTF-8 on return.
+    let mut g = Guard { len: buf.len(), buf: unsafe { buf.as_mut_vec() } };
+    let ret = f(g.buf);
+    // SAFETY: the caller promises to only append data to `buf`
+    let appended = unsafe { g.buf.get_unchecked(g.len..) };
+    if str::from_utf8(appended).is_err() {
+        ret.and(Err(Error::INVALID_UTF8))
+    } else {
+        g.len = g.buf.len();
+        ret
+    }
+}
+
+// Here we must serve many masters with conflicting goals:
+//
+// - avoid allocating unless necessary
+// - avoid overallocating if we know the exact size (#89165)
+// - avoid passing large buffers to readers that always initialize the free capacity if they perform short reads (#23815, #23820)
+// - pass large buffers to readers that do not initialize the spare capacity. this can amortize per-call overheads
+// - and finally pass not-too-small and not-too-large buffers to Windows read APIs because they manage to suffer from both problems
+//   at the same time, i.e. small reads suffer from syscall overhead, all reads incur costs proportional to buffer size (#110650)
+//
+// Unlike std, we don't have `read_buf`, so the spare capacity is zero-initialized
+// before being passed to `read`. The bytes initialized in the previous iteration
+// are not zeroed again.
+#[cfg(feature = "alloc")]
+pub(crate) fn default_read_to_end<R: Read + ?Sized>(
+    r: &mut R,
+    buf: &mut Vec<u8>,
+    size_hint: Option<usize>,
+) -> Result<usize> {
+    const PROBE_SIZE: usize = 32;
+
+    fn small_probe_read<R: Read + ?Sized>(r: &mut R, buf: &mut Vec<u8>) -> Result<usize> {
+        let mut probe = [0_u8; PROBE_SIZE];
+
+        loop {
+            match r.read(&mut probe) {
+                Ok(n) => {
+                    // there is no way to recover from allocation failure here
+                    // because the data has already been read.
+                    buf.extend_from_slice(&probe[..n]);
+                    return Ok(n);
+                }
+                Err(ref e) if e.is_interrupted() => {}
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    let start_len = buf.len();
+    let start_cap = buf.capacity();
+    // Optionally limit the maximum bytes read on each iteration.
+    // This adds an arbitrary fiddle factor to allow for more data than we expect.
+    let mut max_read_size = size_hint
+        .and_then(|s| s.checked_add(1024)?.checked_add(DEFAULT_BUF_SIZE - 1))
+        .map_or(DEFAULT_BUF_SIZE, |s| s / DEFAULT_BUF_SIZE * DEFAULT_BUF_SIZE);
+
+    // avoid inflating empty/small vecs before we have determined that there's anything to read
+    if (size_hint.is_none() || size_hint == Some(0)) && buf.capacity() - buf.len() < PROBE_SIZE {
+        let read = small_probe_read(r, buf)?;
+
+        if read == 0 {
+            return Ok(0);
+        }
+    }
+
+    // Extra initialized bytes from previous loop iteration
+    let mut initialized = 0;
+    loop {
+        if buf.len() == buf.capacity() && buf.capacity() == start_cap {
+            // The buffer might be an exact fit. Let's read into a probe buffer
+            // and see if it returns `Ok(0)`. If so, we've avoided an
+            // unnecessary doubling of the capacity. But if not, append the
+            // probe buffer to the primary buffer and let its capacity grow.
+            let read = small_probe_read(r, buf)?;
+
+            if read == 0 {
+                return Ok(buf.len() - start_len);
+            }
+        }
+
+        if buf.len() == buf.capacity() {
+            // buf is full, need more space
+            buf.try_reserve(PROBE_SIZE)?;
+        }
+
+        let len = buf.len();
+        let buf_len = cmp::min(buf.capacity() - len, max_read_size);
+        // SAFETY: the first `initialized` bytes of the spare capacity have been
+        // initialized by the previous iteration, and `buf_len` is not greater
+        // than the spare capacity.
+        unsafe { buf.set_len(len + cmp::min(initialized, buf_len)) }
+        buf.resize(len + buf_len, 0);
+
+        let bytes_read = match r.read(&mut buf[len..]) {
+            Ok(n) => n,
+            Err(e) => {
+                buf.truncate(len);
+                if e.is_interrupted() {
+                    initialized = buf_len;
+                    continue;
+                }
+                return Err(e);
+            }
+        };
+        initialized = buf_len - bytes_read;
+        buf.truncate(len + bytes_read);
+
+        if bytes_read == 0 {
+            return Ok(buf.len() - start_len);
+        }
+
+        // Use heuristics to determine the max read size if no initial size hint was provided
+        if size_hint.is_none() {
+            // we have passed a larger buffer than previously and the
+            // reader still hasn't returned a short read
+            if buf_len >= max_read_size && bytes_read == buf_len {
+                max_read_size = max_read_size.saturating_mul(2);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub(crate) fn default_read_to_string<R: Read + ?Sized>(
+    r: &mut R,
+    buf: &mut String,
+    size_hint: Option<usize>,
+) -> Result<usize> {
+    // Note that we do *not* call `r.read_to_end()` here. We are passing
+    // `&mut Vec<u8>` (the raw contents of `buf`) into the `read_to_end`
+    // method to fill it up. An arbitrary implementation could overwrite the
+    // entire contents of the vector, not just append to it (which is what
+    // we are expecting).
+    //
+    // To prevent extraneously checking the UTF-8-ness of the entire buffer
+    // we pass it to our hardcoded `default_read_to_end` implementation which
+    // we know is guaranteed to only read data into the end of the buffer.
+    // SAFETY: `default_read_to_end` only appends to the buffer.
+    unsafe { append_to_string(buf, |b| default_read_to_end(r, b, size_hint)) }
+}
+
+#[cfg(feature = "alloc")]
+fn read_until<R: BufRead + ?Sized>(r: &mut R, delim: u8, buf: &mut Vec<u8>) -> Result<usize> {
+    let mut read = 0;
+    loop {
+        let (done, used) = {
+            let available = match r.fill_buf() {
+                Ok(n) => n,
+                Err(ref e) if e.is_interrupted() => continue,
+                Err(e) => return Err(e),
+            };
+            match memchr(delim, available) {
+                Some(i) => {
+                    buf.try_reserve(i + 1)?;
+                    buf.extend_from_slice(&available[..=i]);
+                    (true, i + 1)
+                }
+                None => {
+                    buf.try_reserve(available.len())?;
+                    buf.extend_from_slice(available);
+                    (false, available.len())
+                }
+            }
+        };
+        r.consume(used);
+        read += used;
+        if done || used == 0 {
+            return Ok(read);
+        }
+    }
+}
+
+fn skip_until<R: BufRead + ?Sized>(r: &mut R, delim: u8) -> Result<usize> {
+    let mut read = 0;
+    loop {
+        let (done, used) = {
+            let available = match r.fill_buf() {
+                Ok(n) => n,
+                Err(ref e) if e.is_interrupted() => continue,
+                Err(e) => return Err(e),
+            };
+            match memchr(delim, available) {
+                Some(i) => (true, i + 1),
+                None => (false, available.len()),
+            }
+        };
+        r.consume(used);
+        read += used;
+        if done || used == 0 {
+            return Ok(read);
+        }
+    }
+}
+
+fn read_line_into<'a, R: BufRead + ?Sized>(r: &mut R, buf: &'a mut [u8]) -> Result<&'a str> {
+    let mut read = 0;
+    loop {
+        let (done, used) = {
+            let available = match r.fill_buf() {
+                Ok(n) => n,
+                Err(ref e) if e.is_interrupted() => continue,
+                Err(e) => return Err(e),
+            };
+            let rem = &mut buf[read..];
+            match memchr(b'\n', available) {
+                Some(i) if i < rem.len() => {
+                    rem[..=i].copy_from_slice(&available[..=i]);
+                    (true, i + 1)
+                }
+                _ => {
+                    let n = cmp::min(available.len(), rem.len());
+                    rem[..n].copy_from_slice(&available[..n]);
+                    (available.is_empty(), n)
+                }
+            }
+        };
+        r.consume(used);
+        read += used;
+        if done {
+            break;
+        }
+        if read == buf.len() {
+            // `buf` is full, but this is not an error if the line ends at EOF.
+            let eof = loop {
+                match r.fill_buf() {
+                    Ok(available) => break available.is_empty(),
+                    Err(ref e) if e.is_interrupted() => {}
+                    Err(e) => return Err(e),
+                }
+            };
+            if !eof {
+                return Err(Error::LINE_TOO_LONG);
+            }
+            break;
+        }
+    }
+    str::from_utf8(&buf[..read]).map_err(|_| Error::INVALID_UTF8)
+}
+
+#[inline]
+fn memchr(x: u8, text: &[u8]) -> Option<usize> {
+    text.iter().position(|&b| b == x)
 }
 
 /// The `no_std` subset of `std::io::Write`.
@@ -200,22 +648,293 @@ pub trait Seek {
         Ok(())
     }
 
-    // /// Returns the current seek position from the start of the stream.
-    // ///
-    // /// This is equivalent to `self.seek(SeekFrom::Current(0))`.
-    // fn stream_position(&mut self) -> Result<u64> {
-    //     self.seek(SeekFrom::Current(0))
-    // }
+    /// Returns the current seek position from the start of the stream.
+    ///
+    /// This is equivalent to `self.seek(SeekFrom::Current(0))`.
+    fn stream_position(&mut self) -> Result<u64> {
+        self.seek(SeekFrom::Current(0))
+    }
+
+    /// Seeks relative to the current position.
+    ///
+    /// This is equivalent to `self.seek(SeekFrom::Current(offset))` but
+    /// doesn't return the new position which can allow some implementations
+    /// such as [`BufReader`] to perform more efficient seeks.
+    fn seek_relative(&mut self, offset: i64) -> Result<()> {
+        self.seek(SeekFrom::Current(offset))?;
+        Ok(())
+    }
+}
+
+/// An iterator over the contents of an instance of `BufRead` split on a
+/// particular byte.
+///
+/// This struct is generally created by calling [`split`] on a `BufRead`.
+/// Please see the documentation of [`split`] for more details.
+///
+/// [`split`]: BufRead::split
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug)]
+pub struct Split<B> {
+    buf: B,
+    delim: u8,
+}
+
+#[cfg(feature = "alloc")]
+impl<B: BufRead> Iterator for Split<B> {
+    type Item = Result<Vec<u8>>;
+
+    fn next(&mut self) -> Option<Result<Vec<u8>>> {
+        let mut buf = Vec::new();
+        match self.buf.read_until(self.delim, &mut buf) {
+            Ok(0) => None,
+            Ok(_n) => {
+                if buf[buf.len() - 1] == self.delim {
+                    buf.pop();
+                }
+                Some(Ok(buf))
+            }
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+/// An iterator over the lines of an instance of `BufRead`.
+///
+/// This struct is generally created by calling [`lines`] on a `BufRead`.
+/// Please see the documentation of [`lines`] for more details.
+///
+/// [`lines`]: BufRead::lines
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug)]
+pub struct Lines<B> {
+    buf: B,
+}
+
+#[cfg(feature = "alloc")]
+impl<B: BufRead> Iterator for Lines<B> {
+    type Item = Result<String>;
+
+    fn next(&mut self) -> Option<Result<String>> {
+        let mut buf = String::new();
+        match self.buf.read_line(&mut buf) {
+            Ok(0) => None,
+            Ok(_n) => {
+                if buf.ends_with('\n') {
+                    buf.pop();
+                    if buf.ends_with('\r') {
+                        buf.pop();
+                    }
+                }
+                Some(Ok(buf))
+            }
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+/// Adapter to chain together two readers.
+///
+/// This struct is generally created by calling [`chain`] on a reader.
+/// Please see the documentation of [`chain`] for more details.
+///
+/// [`chain`]: Read::chain
+#[derive(Debug)]
+pub struct Chain<T, U> {
+    first: T,
+    second: U,
+    done_first: bool,
+}
+
+impl<T, U> Chain<T, U> {
+    /// Consumes the `Chain`, returning the wrapped readers.
+    pub fn into_inner(self) -> (T, U) {
+        (self.first, self.second)
+    }
+
+    /// Gets references to the underlying readers in this `Chain`.
+    pub fn get_ref(&self) -> (&T, &U) {
+        (&self.first, &self.second)
+    }
+
+    /// Gets mutable references to the underlying readers in this `Chain`.
+    ///
+    /// Care should be taken to avoid modifying the internal I/O state of the
+    /// underlying readers as doing so may corrupt the internal state of this
+    /// `Chain`.
+    pub fn get_mut(&mut self) -> (&mut T, &mut U) {
+        (&mut self.first, &mut self.second)
+    }
+}
+
+impl<T: Read, U: Read> Read for Chain<T, U> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        if !self.done_first {
+            match self.first.read(buf)? {
+                0 if !buf.is_empty() => self.done_first = true,
+                n => return Ok(n),
+            }
+        }
+        self.second.read(buf)
+    }
+    #[cfg(feature = "alloc")]
+    fn read_to_end(&mut self, buf: &mut Vec<u8>) -> Result<usize> {
+        let mut read = 0;
+        if !self.done_first {
+            read += self.first.read_to_end(buf)?;
+            self.done_first = true;
+        }
+        read += self.second.read_to_end(buf)?;
+        Ok(read)
+    }
+}
+
+impl<T: BufRead, U: BufRead> BufRead for Chain<T, U> {
+    fn fill_buf(&mut self) -> Result<&[u8]> {
+        if !self.done_first {
+            match self.first.fill_buf()? {
+                [] => self.done_first = true,
+                buf => return Ok(buf),
+            }
+        }
+        self.second.fill_buf()
+    }
+    fn consume(&mut self, amt: usize) {
+        if self.done_first {
+            self.second.consume(amt);
+        } else {
+            self.first.consume(amt);
+        }
+    }
+    #[cfg(feature = "alloc")]
+    fn read_until(&mut self, byte: u8, buf: &mut Vec<u8>) -> Result<usize> {
+        let mut read = 0;
+        if !self.done_first {
+            let n = self.first.read_until(byte, buf)?;
+            read += n;
+
+            match buf.last() {
+                Some(b) if *b == byte && n != 0 => return Ok(read),
+                _ => self.done_first = true,
+            }
+        }
+        read += self.second.read_until(byte, buf)?;
+        Ok(read)
+    }
+}
+
+/// Reader adapter which limits the bytes read from an underlying reader.
+///
+/// This struct is generally created by calling [`take`] on a reader.
+/// Please see the documentation of [`take`] for more details.
+///
+/// [`take`]: Read::take
+#[derive(Debug)]
+pub struct Take<T> {
+    inner: T,
+    limit: u64,
+}
+
+impl<T> Take<T> {
+    /// Returns the number of bytes that can be read before this instance will
+    /// return EOF.
+    pub fn limit(&self) -> u64 {
+        self.limit
+    }
+
+    /// Sets the number of bytes that can be read before this instance will
+    /// return EOF. This is the same as constructing a new `Take` instance, so
+    /// the amount of bytes read and the previous limit value don't matter when
+    /// calling this method.
+    pub fn set_limit(&mut self, limit: u64) {
+        self.limit = limit;
+    }
+
+    /// Consumes the `Take`, returning the wrapped reader.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    /// Gets a reference to the underlying reader.
+    pub fn get_ref(&self) -> &T {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    ///
+    /// Care should be taken to avoid modifying the internal I/O state of the
+    /// underlying reader as doing so may corrupt the internal limit of this
+    /// `Take`.
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+}
+
+impl<T: Read> Read for Take<T> {
+    #[allow(clippy::cast_possible_truncation)]
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        // Don't call into inner reader at all at EOF because it may still block
+        if self.limit == 0 {
+            return Ok(0);
+        }
+
+        let max = cmp::min(buf.len() as u64, self.limit) as usize;
+        let n = self.inner.read(&mut buf[..max])?;
+        assert!(n as u64 <= self.limit, "number of read bytes exceeds limit");
+        self.limit -= n as u64;
+        Ok(n)
+    }
+}
+
+impl<T: BufRead> BufRead for Take<T> {
+    #[allow(clippy::cast_possible_truncation)]
+    fn fill_buf(&mut self) -> Result<&[u8]> {
+        // Don't call into inner reader at all at EOF because it may still block
+        if self.limit == 0 {
+            return Ok(&[]);
+        }
+
+        let buf = self.inner.fill_buf()?;
+        let cap = cmp::min(buf.len() as u64, self.limit) as usize;
+        Ok(&buf[..cap])
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn consume(&mut self, amt: usize) {
+        // Don't let callers reset the limit by passing an overlarge value
+        let amt = cmp::min(amt as u64, self.limit) as usize;
+        self.limit -= amt as u64;
+        self.inner.consume(amt);
+    }
+}
+
+/// An iterator over `u8` values of a reader.
+///
+/// This struct is generally created by calling [`bytes`] on a reader.
+/// Please see the documentation of [`bytes`] for more details.
+///
+/// [`bytes`]: Read::bytes
+#[derive(Debug)]
+pub struct Bytes<R> {
+    inner: R,
+}
 
-    // /// Seeks relative to the current position.
-    // ///
-    // /// This is equivalent to `self.seek(SeekFrom::Current(offset))` but
-    // /// doesn't return the new position which can allow some implementations
-    // /// such as [`BufReader`] to perform more efficient seeks.
-    // fn seek_relative(&mut self, offset: i64) -> Result<()> {
-    //     self.seek(SeekFrom::Current(offset))?;
-    //     Ok(())
-    // }
+impl<R: Read> Iterator for Bytes<R> {
+    type Item = Result<u8>;
+
+    fn next(&mut self) -> Option<Result<u8>> {
+        let mut byte = 0;
+        loop {
+            return match self.inner.read(core::slice::from_mut(&mut byte)) {
+                Ok(0) => None,
+                Ok(..) => Some(Ok(byte)),
+                Err(ref e) if e.is_interrupted() => continue,
+                Err(e) => Some(Err(e)),
+            };
+        }
+    }
 }
 
 /// Enumeration of possible methods to seek within an I/O object.
@@ -232,11 +951,144 @@ pub enum SeekFrom {
     /// It is possible to seek beyond the end of an object, but it's an error to
     /// seek before byte 0.
     End(i64),
-    // TODO(arm_compat): It appears that SeekFrom::Current cannot be implemented with APIs provided by Arm semihosting...
-    // /// Sets the offset to the current position plus the specified number of
-    // /// bytes.
-    // ///
-    // /// It is possible to seek beyond the end of an object, but it's an error to
-    // /// seek before byte 0.
-    // Current(i64),
+    /// Sets the offset to the current position plus the specified number of
+    /// bytes.
+    ///
+    /// It is possible to seek beyond the end of an object, but it's an error to
+    /// seek before byte 0.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// Arm semihosting doesn't provide a way to query the current position, so
+    /// [`fs::File`](crate::fs::File) tracks its position on AArch64, Arm, RISC-V,
+    /// LoongArch, and Xtensa (openocd-semihosting). A `File` created from an
+    /// [`OwnedFd`](crate::fd::OwnedFd) is assumed to be positioned at the start of the file.
+    Current(i64),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BufRead as _, ErrorKind, Read as _, Write as _};
+
+    #[test]
+    fn copy() {
+        let data: [u8; 3000] = core::array::from_fn(|i| u8::try_from(i % 251).unwrap());
+        let mut out = [0; 4000];
+        let mut w = &mut out[..];
+        assert_eq!(super::copy(&mut &data[..], &mut w).unwrap(), 3000);
+        assert_eq!(w.len(), 1000);
+        assert_eq!(out[..3000], data[..]);
+
+        let mut w = &mut out[..10];
+        assert_eq!(super::copy(&mut &data[..], &mut w).unwrap_err().kind(), ErrorKind::WriteZero);
+        assert_eq!(super::copy(&mut super::empty(), &mut super::sink()).unwrap(), 0);
+        assert_eq!(
+            super::copy(&mut super::repeat(1).take(5000), &mut super::sink()).unwrap(),
+            5000
+        );
+    }
+
+    #[test]
+    fn take() {
+        let mut r = (&b"hello world"[..]).take(5);
+        let mut buf = [0; 8];
+        assert_eq!(r.read(&mut buf).unwrap(), 5);
+        assert_eq!(&buf[..5], b"hello");
+        assert_eq!(r.read(&mut buf).unwrap(), 0);
+        assert_eq!(r.limit(), 0);
+        r.set_limit(3);
+        assert_eq!(r.fill_buf().unwrap(), b" wo");
+        r.consume(10);
+        assert_eq!(r.limit(), 0);
+        assert_eq!(r.into_inner(), b"rld");
+    }
+
+    #[test]
+    fn chain() {
+        let mut r = (&b"ab"[..]).chain(&b""[..]).chain(&b"cd"[..]);
+        let mut buf = [0; 8];
+        assert_eq!(r.read(&mut buf).unwrap(), 2);
+        assert_eq!(r.read(&mut buf).unwrap(), 2);
+        assert_eq!(&buf[..2], b"cd");
+        assert_eq!(r.read(&mut buf).unwrap(), 0);
+
+        let mut r = (&b"a\nb"[..]).chain(&b"c\nd"[..]);
+        let mut line = [0; 8];
+        assert_eq!(r.read_line_into(&mut line).unwrap(), "a\n");
+        assert_eq!(r.read_line_into(&mut line).unwrap(), "bc\n");
+        assert_eq!(r.read_line_into(&mut line).unwrap(), "d");
+        assert_eq!(r.read_line_into(&mut line).unwrap(), "");
+    }
+
+    #[test]
+    fn bytes() {
+        let mut b = (&b"xyz"[..]).bytes();
+        assert_eq!(b.next().unwrap().unwrap(), b'x');
+        assert_eq!(b.next().unwrap().unwrap(), b'y');
+        assert_eq!(b.next().unwrap().unwrap(), b'z');
+        assert!(b.next().is_none());
+    }
+
+    #[test]
+    fn util() {
+        let mut buf = [1; 4];
+        assert_eq!(super::empty().read(&mut buf).unwrap(), 0);
+        super::empty().read_exact(&mut []).unwrap();
+        assert_eq!(
+            super::empty().read_exact(&mut buf).unwrap_err().kind(),
+            ErrorKind::UnexpectedEof
+        );
+        assert_eq!(super::empty().fill_buf().unwrap(), b"");
+        assert_eq!(super::empty().write(&buf).unwrap(), 4);
+        assert_eq!(super::repeat(7).read(&mut buf).unwrap(), 4);
+        assert_eq!(buf, [7; 4]);
+        assert_eq!(super::sink().write(&buf).unwrap(), 4);
+        write!(super::sink(), "{}", 1).unwrap();
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn read_to_end() {
+        use alloc::{string::String, vec::Vec};
+
+        use super::{ErrorKind, Read};
+
+        struct Chunked<'a>(&'a [u8], usize);
+        impl Read for Chunked<'_> {
+            fn read(&mut self, buf: &mut [u8]) -> super::Result<usize> {
+                let n = buf.len().min(self.0.len()).min(self.1);
+                buf[..n].copy_from_slice(&self.0[..n]);
+                self.0 = &self.0[n..];
+                Ok(n)
+            }
+        }
+
+        let data: Vec<u8> = (0..5000_u32).map(|i| (i % 251) as u8).collect();
+        for &chunk in &[1, 7, 32, 4096, usize::MAX] {
+            let mut v = Vec::from(&b"x"[..]);
+            assert_eq!(Chunked(&data, chunk).read_to_end(&mut v).unwrap(), data.len());
+            assert_eq!(v[0], b'x');
+            assert_eq!(v[1..], data[..]);
+
+            for hint in [None, Some(0), Some(10), Some(data.len())] {
+                let mut v = Vec::new();
+                let n =
+                    super::default_read_to_end(&mut Chunked(&data, chunk), &mut v, hint).unwrap();
+                assert_eq!(n, data.len());
+                assert_eq!(v, data);
+            }
+        }
+        let mut v = Vec::new();
+        assert_eq!(Chunked(b"", 1).read_to_end(&mut v).unwrap(), 0);
+        assert!(v.is_empty());
+
+        let mut s = String::from("a");
+        assert_eq!(Chunked(b"bc", 1).read_to_string(&mut s).unwrap(), 2);
+        assert_eq!(s, "abc");
+        assert_eq!(
+            Chunked(b"d\xff", 1).read_to_string(&mut s).unwrap_err().kind(),
+            ErrorKind::InvalidData
+        );
+        assert_eq!(s, "abc");
+    }
 }
@@ -60,13 +60,26 @@ pub struct Stdout(sys::stdio::StdioFd);
 pub struct Stderr(sys::stdio::StdioFd);
 
 impl_as_fd!(Stdin, Stdout, Stderr);
-// TODO(stdio): std provides io trait implementations on &Std{in,out,err} as they uses locks.
 impl io::Read for Stdin {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        (&*self).read(buf)
+    }
+}
+impl io::Read for &Stdin {
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
         sys::read(self.as_fd(), buf)
     }
 }
 impl io::Write for Stdout {
+    fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+        (&*self).write(bytes)
+    }
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        (&*self).flush()
+    }
+}
+impl io::Write for &Stdout {
     fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
         sys::write(self.as_fd(), bytes)
     }
@@ -76,6 +89,15 @@ impl io::Write for Stdout {
     }
 }
 impl io::Write for Stderr {
+    fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+        (&*self).write(bytes)
+    }
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        (&*self).flush()
+    }
+}
+impl io::Write for &Stderr {
     fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
         sys::write(self.as_fd(), bytes)
     }
@@ -84,6 +106,132 @@ impl io::Write for Stderr {
         Ok(())
     }
 }
+
+impl Stdout {
+    /// Returns a line-buffered handle to this standard output stream.
+    ///
+    /// Writes to the returned [`StdoutLock`] are accumulated in a fixed-size
+    /// internal buffer, which is flushed when a newline is written, when the
+    /// buffer is full, and when the lock is dropped. This means that a line
+    /// written in many small pieces (e.g., by [`println!`](crate::println)) is
+    /// emitted with a single semihosting call.
+    ///
+    /// Unlike [`std::io::Stdout::lock`], this does not acquire a mutex. Since the
+    /// host performs each semihosting call atomically, output from multiple
+    /// cores is not interleaved in the middle of a line, as long as the line
+    /// fits in the internal buffer.
+    ///
+    /// [`std::io::Stdout::lock`]: https://doc.rust-lang.org/std/io/struct.Stdout.html#method.lock
+    #[must_use]
+    pub fn lock(&self) -> StdoutLock<'_> {
+        StdoutLock { inner: self, buf: [0; STDOUT_BUF_SIZE], len: 0 }
+    }
+}
+
+const STDOUT_BUF_SIZE: usize = 256;
+
+/// A line-buffered handle to the standard output stream.
+///
+/// Created by the [`Stdout::lock`] method.
+pub struct StdoutLock<'a> {
+    inner: &'a Stdout,
+    buf: [u8; STDOUT_BUF_SIZE],
+    len: usize,
+}
+
+impl StdoutLock<'_> {
+    /// Appends `bytes` to the buffer. The caller must ensure that it fits.
+    fn push(&mut self, bytes: &[u8]) {
+        self.buf[self.len..self.len + bytes.len()].copy_from_slice(bytes);
+        self.len += bytes.len();
+    }
+    /// Writes the buffered data to the underlying stream.
+    ///
+    /// On error, the data that has not been written remains in the buffer.
+    fn flush_buf(&mut self) -> io::Result<()> {
+        let mut written = 0;
+        let mut ret = Ok(());
+        while written < self.len {
+            match sys::write(self.inner.as_fd(), &self.buf[written..self.len]) {
+                Ok(0) => {
+                    ret = Err(io::Error::WRITE_ALL_EOF);
+                    break;
+                }
+                Ok(n) => written += n,
+                Err(ref e) if e.is_interrupted() => {}
+                Err(e) => {
+                    ret = Err(e);
+                    break;
+                }
+            }
+        }
+        if written > 0 {
+            self.buf.copy_within(written..self.len, 0);
+            self.len -= written;
+        }
+        ret
+    }
+}
+impl io::Write for StdoutLock<'_> {
+    fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+        let newline = match bytes.iter().rposition(|&b| b == b'\n') {
+            Some(i) => i,
+            None => {
+                // No complete line: just buffer it.
+                if self.len + bytes.len() > self.buf.len() {
+                    self.flush_buf()?;
+                }
+                if bytes.len() >= self.buf.len() {
+                    return sys::write(self.inner.as_fd(), bytes);
+                }
+                self.push(bytes);
+                return Ok(bytes.len());
+            }
+        };
+        let (lines, tail) = bytes.split_at(newline + 1);
+        if self.len + lines.len() > self.buf.len() {
+            // The lines don't fit in the buffer: write the buffered data and
+            // the lines separately.
+            self.flush_buf()?;
+            if lines.len() > self.buf.len() {
+                let n = sys::write(self.inner.as_fd(), lines)?;
+                if n < lines.len() {
+                    return Ok(n);
+                }
+            } else {
+                self.push(lines);
+            }
+        } else {
+            self.push(lines);
+        }
+        if let Err(e) = self.flush_buf() {
+            if self.len >= lines.len() {
+                // None of `lines` has been written, so remove it from the buffer
+                // and report the error without accepting the input.
+                self.len -= lines.len();
+                return Err(e);
+            }
+            // Part of `lines` has been written, and the rest is kept in the buffer.
+            return Ok(lines.len());
+        }
+        let n = tail.len().min(self.buf.len() - self.len);
+        self.push(&tail[..n]);
+        Ok(lines.len() + n)
+    }
+    fn flush(&mut self) -> io::Result<()> {
+        self.flush_buf()
+    }
+}
+impl Drop for StdoutLock<'_> {
+    fn drop(&mut self) {
+        let _ = self.flush_buf();
+    }
+}
+impl fmt::Debug for StdoutLock<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StdoutLock").finish_non_exhaustive()
+    }
+}
 impl fmt::Debug for Stdin {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Stdin").finish_non_exhaustive()
@@ -124,7 +272,6 @@ pub trait IsTerminal: crate::sealed::Sealed {
 }
 macro_rules! impl_is_terminal {
     ($($t:ty),*$(,)?) => {$(
-        impl crate::sealed::Sealed for $t {}
         impl crate::io::IsTerminal for $t {
             #[inline]
             fn is_terminal(&self) -> bool {
@@ -134,6 +281,9 @@ macro_rules! impl_is_terminal {
         }
     )*}
 }
+impl crate::sealed::Sealed for Stdin {}
+impl crate::sealed::Sealed for Stdout {}
+impl crate::sealed::Sealed for Stderr {}
 impl_is_terminal!(Stdin, Stdout, Stderr);
 #[cfg(feature = "fs")]
 impl_is_terminal!(crate::fs::File);
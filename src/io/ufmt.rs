@@ -0,0 +1,22 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// Implementations of ufmt's uWrite trait.
+
+use crate::io;
+
+macro_rules! impl_uwrite {
+    ($($ty:ty),* $(,)?) => {$(
+        impl ::ufmt::uWrite for $ty {
+            type Error = io::Error;
+            #[inline]
+            fn write_str(&mut self, s: &str) -> io::Result<()> {
+                io::Write::write_all(self, s.as_bytes())
+            }
+        }
+    )*};
+}
+
+#[cfg(feature = "stdio")]
+impl_uwrite!(io::Stdout, &io::Stdout, io::StdoutLock<'_>, io::Stderr, &io::Stderr);
+#[cfg(feature = "fs")]
+impl_uwrite!(crate::fs::File, &crate::fs::File);
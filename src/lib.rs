@@ -23,6 +23,7 @@ APIs are categorized into the following four types:
 
 - Helpers that are useful when using this library.
   - `c!`: `CStr` literal macro. (Since Rust 1.77, this macro is soft-deprecated in favor of C string literals (`c"..."`).)
+  - `CStrBuf`/`c_format!`: fixed-size buffer and `format!`-like macro to build `CStr` (e.g., paths) at runtime without `alloc`.
 
 - `semihosting::sys` module provides low-level access to platform-specific semihosting interfaces.
 
@@ -154,6 +155,47 @@ semihosting = { version = "0.1", features = ["stdio", "panic-handler"] }
 
   See [its documentation](https://github.com/taiki-e/portable-atomic#optional-features-critical-section) for details.
 
+- **`embedded-io`**<br>
+  Implement [embedded-io] traits (`Read`, `Write`, `Seek`, `ErrorType`) for `fs::File`, `io::{Stdin,Stdout,Stderr}`.
+
+  `io::Error` implements `embedded_io::Error`, and `io::ErrorKind` can be converted to `embedded_io::ErrorKind`.
+
+  Note:
+  - This requires Rust 1.81+.
+
+- **`embedded-io-async`**<br>
+  Implement [embedded-io-async] traits for the same types as the `embedded-io` feature.
+
+  Semihosting calls are blocking, so the returned futures are always ready on the first poll.
+
+  Note:
+  - This implicitly enables the `embedded-io` feature.
+  - This requires Rust 1.81+.
+
+- **`defmt`**<br>
+  Provide [defmt] global logger that writes encoded frames to stdout or a host file (see `semihosting::defmt::set_output`).
+
+  The output can be decoded on the host by `defmt-print` or other tools based on `defmt-decoder`.
+
+  Note:
+  - You need to provide a [critical-section] implementation, and pass `-C link-arg=-Tdefmt.x` to the linker.
+  - This implicitly enables the `stdio` and `fs` features.
+  - This requires Rust 1.76+.
+
+- **`log`**<br>
+  Provide [log] backend that writes `[LEVEL target] message` lines to stderr (see `semihosting::log::init`).
+
+  If the `time` feature is also enabled, each line is prefixed with the elapsed time since the start of the execution.
+
+  Note:
+  - This implicitly enables the `stdio` feature.
+  - This requires Rust 1.71+.
+
+- **`ufmt`**<br>
+  Implement [ufmt]'s `uWrite` trait for `fs::File`, `io::{Stdout,Stderr,StdoutLock}`.
+
+  If the `stdio` feature is also enabled, this also enables `semihosting::{uprint*,ueprint*,udbg}`, which are similar to `semihosting::{print*,eprint*,dbg}`, but use ufmt instead of `core::fmt` to reduce code size.
+
 - **`args`**<br>
   Enable `semihosting::experimental::env::args`.
 
@@ -232,8 +274,14 @@ semihosting = { version = "0.1", features = ["stdio", "panic-handler"] }
     llvm-addr2line -fipe <path/to/binary> | rustfilt
     ```
 
+[critical-section]: https://github.com/rust-embedded/critical-section
+[defmt]: https://github.com/knurling-rs/defmt
+[embedded-io]: https://github.com/rust-embedded/embedded-hal/tree/HEAD/embedded-io
+[embedded-io-async]: https://github.com/rust-embedded/embedded-hal/tree/HEAD/embedded-io-async
+[log]: https://github.com/rust-lang/log
 [portable-atomic]: https://github.com/taiki-e/portable-atomic
 [rustfilt]: https://github.com/luser/rustfilt
+[ufmt]: https://github.com/japaric/ufmt
 [unwinding]: https://github.com/nbdd0121/unwinding
 
 <!-- tidy:sync-markdown-to-rustdoc:end -->
@@ -334,7 +382,21 @@ mod utils;
 
 #[cfg(any(
     feature = "panic-unwind",
-    all(feature = "random", any(target_has_atomic = "32", feature = "portable-atomic")),
+    all(
+        any(
+            feature = "random",
+            all(
+                feature = "stdio",
+                not(any(
+                    target_arch = "mips",
+                    target_arch = "mips32r6",
+                    target_arch = "mips64",
+                    target_arch = "mips64r6",
+                )),
+            ),
+        ),
+        any(target_has_atomic = "32", feature = "portable-atomic"),
+    ),
 ))]
 cfg_sel!({
     #[cfg(feature = "portable-atomic")]
@@ -352,6 +414,7 @@ mod macros;
 
 #[macro_use]
 mod c_str;
+pub use self::c_str::CStrBuf;
 
 #[macro_use]
 pub mod fd;
@@ -359,16 +422,20 @@ pub mod fd;
 #[macro_use]
 pub mod io;
 
+#[cfg(feature = "defmt")]
+pub mod defmt;
 #[cfg(any(feature = "args", feature = "panic-unwind", feature = "time", feature = "random"))]
 pub mod experimental;
 #[cfg(feature = "fs")]
 pub mod fs;
+#[cfg(feature = "log")]
+pub mod log;
 #[cfg(feature = "panic-handler")]
 mod panicking;
 pub mod process;
 pub mod sys;
 
-#[cfg(feature = "stdio")]
+#[cfg(any(feature = "stdio", feature = "fs"))]
 mod sealed {
     #[allow(unknown_lints, unnameable_types)] // Not public API. unnameable_types is available on Rust 1.79+
     pub trait Sealed {}
@@ -381,11 +448,15 @@ pub mod __private {
     pub use core::{
         concat,
         ffi::CStr,
-        file, line,
+        file, format_args, line,
         result::Result::{Err, Ok},
         stringify, write, writeln,
     };
 
+    #[cfg(all(feature = "stdio", feature = "ufmt"))]
+    #[doc(hidden)]
+    pub use ufmt;
+
     #[doc(hidden)]
     pub use crate::c_str::const_c_str_check;
 }
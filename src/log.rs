@@ -0,0 +1,227 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! [log] backend that writes log records to the standard error.
+//!
+//! Each record is written as a single line of the following form:
+//!
+//! ```text
+//! [INFO my_app::module] message
+//! ```
+//!
+//! When the `time` feature is also enabled, the line is prefixed with the time
+//! elapsed since the start of the execution (if the platform supports it; see
+//! [`Instant`](crate::experimental::time::Instant) for details):
+//!
+//! ```text
+//! [1.230s INFO my_app::module] message
+//! ```
+//!
+//! The line is formatted into a fixed-size buffer on the stack and written to
+//! the host with a single semihosting call, so lines from different records
+//! never interleave. The buffer is 256 bytes; longer lines are split and
+//! written with multiple semihosting calls, so they may interleave with other
+//! output.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! semihosting::log::init(log::LevelFilter::Info).unwrap();
+//! log::info!("hello");
+//! ```
+//!
+//! [log]: https://github.com/rust-lang/log
+
+use core::{fmt, time::Duration};
+
+use ::log::{LevelFilter, Log, Metadata, Record, SetLoggerError};
+
+use crate::{io, utils::ChunkBuf};
+
+/// The size of the buffer used to format a log record.
+const BUF_SIZE: usize = 256;
+
+static LOGGER: Logger = Logger;
+
+/// Installs the semihosting logger as the global logger and sets the maximum
+/// log level to `level`.
+///
+/// # Errors
+///
+/// Returns an error if a global logger has already been set.
+#[cfg(target_has_atomic = "ptr")]
+pub fn init(level: LevelFilter) -> Result<(), SetLoggerError> {
+    ::log::set_logger(&LOGGER)?;
+    ::log::set_max_level(level);
+    Ok(())
+}
+
+/// A thread-unsafe version of [`init`].
+///
+/// This function is available on all platforms, even those that do not have
+/// support for atomics that is needed by [`init`].
+///
+/// # Errors
+///
+/// Returns an error if a global logger has already been set.
+///
+/// # Safety
+///
+/// This function is only safe to call when it cannot race with any other
+/// calls to `init`, `init_racy`, or the logger initialization functions of the
+/// `log` crate.
+///
+/// See [`log::set_logger_racy`](::log::set_logger_racy) for details.
+pub unsafe fn init_racy(level: LevelFilter) -> Result<(), SetLoggerError> {
+    // SAFETY: the caller must uphold the safety contract.
+    unsafe {
+        ::log::set_logger_racy(&LOGGER)?;
+        ::log::set_max_level_racy(level);
+    }
+    Ok(())
+}
+
+struct Logger;
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.level() <= ::log::max_level()
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        if let Ok(stderr) = io::stderr() {
+            let mut buf = LineBuf { buf: ChunkBuf::new(), out: stderr };
+            let _ = write_record(&mut buf, record, elapsed());
+            buf.flush();
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Returns the time elapsed since the start of the execution, if available.
+fn elapsed() -> Option<Duration> {
+    #[cfg(feature = "time")]
+    {
+        use crate::sys::time::Instant;
+        Instant::now().ok().and_then(|now| now.checked_sub_instant(&Instant::START))
+    }
+    #[cfg(not(feature = "time"))]
+    {
+        None
+    }
+}
+
+fn write_record(
+    f: &mut impl fmt::Write,
+    record: &Record<'_>,
+    elapsed: Option<Duration>,
+) -> fmt::Result {
+    f.write_str("[")?;
+    if let Some(d) = elapsed {
+        write!(f, "{}.{:03}s ", d.as_secs(), d.subsec_millis())?;
+    }
+    writeln!(f, "{} {}] {}", record.level(), record.target(), record.args())
+}
+
+/// Buffer that accumulates a line and writes it to `out` at once.
+struct LineBuf<W> {
+    buf: ChunkBuf<BUF_SIZE>,
+    out: W,
+}
+
+impl<W: io::Write> LineBuf<W> {
+    fn flush(&mut self) {
+        let Self { buf, out } = self;
+        buf.flush(|b| write_ignoring_errors(out, b));
+    }
+}
+
+impl<W: io::Write> fmt::Write for LineBuf<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let Self { buf, out } = self;
+        buf.write(s.as_bytes(), |b| write_ignoring_errors(out, b));
+        Ok(())
+    }
+}
+
+fn write_ignoring_errors<W: io::Write>(out: &mut W, bytes: &[u8]) {
+    // `Log::log` has no way to report errors, so records that could not be
+    // written are dropped.
+    let _ = out.write_all(bytes);
+}
+
+#[cfg(test)]
+#[allow(clippy::std_instead_of_alloc, clippy::std_instead_of_core)]
+mod tests {
+    use core::{fmt, time::Duration};
+    use std::{string::String, vec::Vec};
+
+    use ::log::{Level, LevelFilter, Log as _, Metadata, Record};
+
+    use super::{BUF_SIZE, LineBuf, Logger, write_record};
+    use crate::{io, utils::ChunkBuf};
+
+    /// Writer that records each write.
+    struct Chunks(Vec<Vec<u8>>);
+    impl io::Write for Chunks {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.push(buf.to_vec());
+            Ok(buf.len())
+        }
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn format(
+        level: Level,
+        target: &str,
+        args: fmt::Arguments<'_>,
+        elapsed: Option<Duration>,
+    ) -> Vec<Vec<u8>> {
+        let mut buf = LineBuf { buf: ChunkBuf::new(), out: Chunks(Vec::new()) };
+        let record = &Record::builder().args(args).level(level).target(target).build();
+        write_record(&mut buf, record, elapsed).unwrap();
+        buf.flush();
+        buf.out.0
+    }
+
+    #[test]
+    fn format_record() {
+        assert_eq!(format(Level::Info, "my_app::module", format_args!("hello {}", 1), None), [
+            b"[INFO my_app::module] hello 1\n"
+        ]);
+        assert_eq!(
+            format(Level::Warn, "a", format_args!("b"), Some(Duration::from_millis(1230))),
+            [b"[1.230s WARN a] b\n"]
+        );
+    }
+
+    #[test]
+    fn long_line() {
+        // Lines longer than the buffer are written with multiple writes.
+        let msg: String = "a".repeat(BUF_SIZE);
+        let chunks = format(Level::Error, "t", format_args!("{msg}"), None);
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].len(), BUF_SIZE);
+        let line = std::format!("[ERROR t] {msg}\n");
+        assert_eq!(chunks.concat(), line.as_bytes());
+        // Lines that fit in the buffer are written at once.
+        let msg = &msg[..msg.len() + BUF_SIZE - line.len()];
+        assert_eq!(format(Level::Error, "t", format_args!("{msg}"), None).len(), 1);
+    }
+
+    #[test]
+    fn level_filter() {
+        let meta = |level| Metadata::builder().level(level).build();
+        ::log::set_max_level(LevelFilter::Warn);
+        assert!(Logger.enabled(&meta(Level::Error)));
+        assert!(Logger.enabled(&meta(Level::Warn)));
+        assert!(!Logger.enabled(&meta(Level::Info)));
+        ::log::set_max_level(LevelFilter::Off);
+        assert!(!Logger.enabled(&meta(Level::Error)));
+    }
+}
@@ -6,9 +6,9 @@
 #[macro_export]
 macro_rules! print {
     ($($tt:tt)*) => {
-        if let $crate::__private::Ok(mut stdout) = $crate::io::stdout() {
+        if let $crate::__private::Ok(stdout) = $crate::io::stdout() {
             use $crate::io::Write as _;
-            let _ = $crate::__private::write!(stdout, $($tt)*);
+            let _ = $crate::__private::write!(stdout.lock(), $($tt)*);
         }
     };
 }
@@ -16,9 +16,9 @@ macro_rules! print {
 #[macro_export]
 macro_rules! println {
     ($($tt:tt)*) => {
-        if let $crate::__private::Ok(mut stdout) = $crate::io::stdout() {
+        if let $crate::__private::Ok(stdout) = $crate::io::stdout() {
             use $crate::io::Write as _;
-            let _ = $crate::__private::writeln!(stdout, $($tt)*);
+            let _ = $crate::__private::writeln!(stdout.lock(), $($tt)*);
         }
     };
 }
@@ -68,3 +68,76 @@ macro_rules! dbg {
         ($($crate::dbg!($val)),+,)
     };
 }
+
+#[cfg(all(feature = "stdio", feature = "ufmt"))]
+#[macro_export]
+macro_rules! uprint {
+    ($($tt:tt)*) => {
+        if let $crate::__private::Ok(stdout) = $crate::io::stdout() {
+            use $crate::__private::ufmt;
+            let _ = ufmt::uwrite!(stdout.lock(), $($tt)*);
+        }
+    };
+}
+#[cfg(all(feature = "stdio", feature = "ufmt"))]
+#[macro_export]
+macro_rules! uprintln {
+    () => {
+        $crate::uprint!("\n")
+    };
+    ($($tt:tt)*) => {
+        if let $crate::__private::Ok(stdout) = $crate::io::stdout() {
+            use $crate::__private::ufmt;
+            let _ = ufmt::uwriteln!(stdout.lock(), $($tt)*);
+        }
+    };
+}
+
+#[cfg(all(feature = "stdio", feature = "ufmt"))]
+#[macro_export]
+macro_rules! ueprint {
+    ($($tt:tt)*) => {
+        if let $crate::__private::Ok(mut stderr) = $crate::io::stderr() {
+            use $crate::__private::ufmt;
+            let _ = ufmt::uwrite!(stderr, $($tt)*);
+        }
+    };
+}
+#[cfg(all(feature = "stdio", feature = "ufmt"))]
+#[macro_export]
+macro_rules! ueprintln {
+    () => {
+        $crate::ueprint!("\n")
+    };
+    ($($tt:tt)*) => {
+        if let $crate::__private::Ok(mut stderr) = $crate::io::stderr() {
+            use $crate::__private::ufmt;
+            let _ = ufmt::uwriteln!(stderr, $($tt)*);
+        }
+    };
+}
+
+#[cfg(all(feature = "stdio", feature = "ufmt"))]
+#[macro_export]
+macro_rules! udbg {
+    () => {
+        $crate::ueprintln!("[{}:{}]", $crate::__private::file!(), $crate::__private::line!())
+    };
+    ($val:expr $(,)?) => {
+        match $val {
+            tmp => {
+                $crate::ueprintln!(
+                    "[{}:{}] {} = {:#?}",
+                    $crate::__private::file!(),
+                    $crate::__private::line!(),
+                    $crate::__private::stringify!($val),
+                    &tmp,
+                );
+                tmp
+            }
+        }
+    };
+    ($($val:expr),+ $(,)?) => {
+        ($($crate::udbg!($val)),+,)
+    };
+}
@@ -1,34 +1,321 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use core::ffi::CStr;
+//! Arm semihosting-specific extensions to primitives in the [`semihosting::fs`](crate::fs) module.
+//!
+//! # Special file names
+//!
+//! Arm semihosting interprets some file names specially, and they can also be
+//! opened using [`fs::File`] and [`fs::OpenOptions`]:
+//!
+//! - `:tt`: the console of the host. When opened in read-only mode (e.g., by
+//!   [`File::open`](fs::File::open)), this refers to stdin; when opened in
+//!   write mode (e.g., by [`File::create`](fs::File::create)), this refers to
+//!   stdout; when opened in append mode, this refers to stderr.
+//! - `:semihosting-features`: the [semihosting extensions] supported by the
+//!   host. This must be opened in read-only mode (e.g., by [`File::open`](fs::File::open)).
+//!
+//! Note that these are not regular files: for example, [`File::metadata`](fs::File::metadata)
+//! and seeking may fail on `:tt`.
+//!
+//! [semihosting extensions]: https://github.com/ARM-software/abi-aa/blob/2025Q4/semihosting/semihosting.rst#semihosting-extensions
 
-use super::{OpenMode, sys_flen, sys_open, sys_seek};
+use core::{
+    ffi::CStr,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use super::{OpenMode, errno, sys_flen, sys_open, sys_seek};
 pub(crate) use super::{sys_remove as unlink, sys_rename as rename};
 use crate::{
-    fd::{BorrowedFd, OwnedFd},
-    fs, io,
+    fd::{AsFd as _, BorrowedFd, OwnedFd},
+    fs, io, sys,
 };
 
+/// The position of a file opened in append mode after a write.
+///
+/// `sys_seek` rejects positions larger than `isize::MAX`, so this is never a valid position.
+const AT_END: usize = usize::MAX;
+
+pub(crate) struct File {
+    fd: OwnedFd,
+    // Arm semihosting only provides SYS_SEEK which takes an absolute position
+    // and has no way to query the current position, so we track the logical
+    // position ourselves to support SeekFrom::Current.
+    // Only atomic load/store are used, so this works on targets without atomic CAS.
+    // As with the OS file offset on other platforms, concurrent reads/writes
+    // via `&File` race on the position.
+    pos: AtomicUsize,
+    append: bool,
+}
+
+impl File {
+    pub(crate) fn open(path: &CStr, options: &fs::OpenOptions) -> io::Result<Self> {
+        let (fd, append) = open(path, options)?;
+        Ok(Self { fd, pos: AtomicUsize::new(0), append })
+    }
+    /// Note that the position is assumed to be at the start of the file.
+    #[inline]
+    pub(crate) fn from_fd(fd: OwnedFd) -> Self {
+        Self { fd, pos: AtomicUsize::new(0), append: false }
+    }
+    #[inline]
+    pub(crate) fn into_fd(self) -> OwnedFd {
+        self.fd
+    }
+    #[inline]
+    pub(crate) fn as_fd(&self) -> BorrowedFd<'_> {
+        self.fd.as_fd()
+    }
+    pub(crate) fn read(&self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = sys::read(self.as_fd(), buf)?;
+        self.advance(n);
+        Ok(n)
+    }
+    pub(crate) fn write(&self, buf: &[u8]) -> io::Result<usize> {
+        let n = sys::write(self.as_fd(), buf)?;
+        if self.append {
+            self.pos.store(AT_END, Ordering::Relaxed);
+        } else {
+            self.advance(n);
+        }
+        Ok(n)
+    }
+    pub(crate) fn read_at(&self, buf: &mut [u8], offset: u64) -> io::Result<usize> {
+        let pos = self.pos.load(Ordering::Relaxed);
+        let res = sys::pread(self.as_fd(), buf, offset);
+        // Restore the position even if the read failed, as the seek may have succeeded.
+        let restored = self.restore_position(pos);
+        let n = res?;
+        restored?;
+        Ok(n)
+    }
+    pub(crate) fn write_at(&self, buf: &[u8], offset: u64) -> io::Result<usize> {
+        let pos = self.pos.load(Ordering::Relaxed);
+        let res = sys::pwrite(self.as_fd(), buf, offset);
+        // Restore the position even if the write failed, as the seek may have succeeded.
+        let restored = self.restore_position(pos);
+        let n = res?;
+        restored?;
+        Ok(n)
+    }
+    fn restore_position(&self, pos: usize) -> io::Result<()> {
+        let abs_pos = if pos == AT_END { sys_flen(self.as_fd())? } else { pos };
+        unsafe { sys_seek(self.as_fd(), abs_pos) }
+    }
+    #[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+    pub(crate) fn seek(&self, pos: io::SeekFrom) -> io::Result<u64> {
+        let abs_pos = match pos {
+            io::SeekFrom::Start(pos) => pos,
+            io::SeekFrom::End(offset) => add_offset(sys_flen(self.as_fd())? as u64, offset)?,
+            io::SeekFrom::Current(offset) => add_offset(self.stream_position()?, offset)?,
+        };
+        // sys_seek may succeed without this guard, but make the behavior consistent with other platforms.
+        let abs_pos = isize::try_from(abs_pos).map_err(|_| io::Error::EINVAL)? as usize;
+        unsafe { sys_seek(self.as_fd(), abs_pos)? }
+        self.pos.store(abs_pos, Ordering::Relaxed);
+        Ok(abs_pos as u64)
+    }
+    pub(crate) fn stream_position(&self) -> io::Result<u64> {
+        match self.pos.load(Ordering::Relaxed) {
+            AT_END => Ok(sys_flen(self.as_fd())? as u64),
+            pos => Ok(pos as u64),
+        }
+    }
+    #[inline]
+    fn advance(&self, n: usize) {
+        let pos = self.pos.load(Ordering::Relaxed);
+        if pos != AT_END {
+            self.pos.store(pos.saturating_add(n), Ordering::Relaxed);
+        }
+    }
+}
+
+// TODO(arm_compat): Arm semihosting doesn't provide Large-file support (LFS).
+#[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+fn add_offset(base: u64, offset: i64) -> io::Result<u64> {
+    let pos = (base as i64).saturating_add(offset);
+    if pos.is_negative() {
+        return Err(io::Error::EINVAL);
+    }
+    Ok(pos as u64)
+}
+
 pub(crate) struct Metadata {
     size: u64,
 }
 
+#[allow(clippy::unused_self)]
 impl Metadata {
     #[inline]
     pub(crate) fn size(&self) -> u64 {
         self.size
     }
+    #[inline]
+    pub(crate) fn file_type(&self) -> FileType {
+        FileType(())
+    }
+    #[inline]
+    pub(crate) fn permissions(&self) -> Permissions {
+        Permissions(())
+    }
+    #[cfg(feature = "time")]
+    pub(crate) fn modified(&self) -> io::Result<sys::time::SystemTime> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+    #[cfg(feature = "time")]
+    pub(crate) fn accessed(&self) -> io::Result<sys::time::SystemTime> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+    #[cfg(feature = "time")]
+    pub(crate) fn created(&self) -> io::Result<sys::time::SystemTime> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+}
+
+// Arm semihosting has no way to query the file type, so the file type is
+// unknown and all `is_*` methods return false.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct FileType(());
+
+#[allow(clippy::unused_self)]
+impl FileType {
+    #[inline]
+    pub(crate) fn is_dir(self) -> bool {
+        false
+    }
+    #[inline]
+    pub(crate) fn is_file(self) -> bool {
+        false
+    }
+    #[inline]
+    pub(crate) fn is_symlink(self) -> bool {
+        false
+    }
+    #[inline]
+    pub(crate) fn is_char_device(self) -> bool {
+        false
+    }
+}
+
+// Arm semihosting has no way to query the permissions, so whether the file is
+// readonly is unknown and `readonly` returns false.
+#[derive(Clone, PartialEq, Eq)]
+pub(crate) struct Permissions(());
+
+#[allow(clippy::unused_self)]
+impl Permissions {
+    #[inline]
+    pub(crate) fn readonly(&self) -> bool {
+        false
+    }
+}
+
+pub(crate) fn copy(from: &CStr, to: &CStr) -> io::Result<u64> {
+    let mut reader = fs::File::open(from)?;
+    // Arm semihosting has no way to check that `from` is a regular file, and
+    // opening a directory succeeds on some hosts. file_copy reads from `from`
+    // before creating `to`, which leaves `to` untouched if `from` cannot be read.
+    io::file_copy(&mut reader, || fs::File::create(to))
+}
+
+#[allow(deprecated)] // fs::tempfile documents that the names are predictable
+#[inline]
+pub(crate) fn tmpnam(id: u8, buf: &mut [u8]) -> io::Result<&CStr> {
+    super::sys_tmpnam(id, buf)
+}
+
+// Arm semihosting has no way to enumerate the entries of a directory.
+pub(crate) enum ReadDir {}
+
+pub(crate) fn read_dir(_path: &CStr) -> io::Result<ReadDir> {
+    Err(io::ErrorKind::Unsupported.into())
+}
+
+impl Iterator for ReadDir {
+    type Item = io::Result<DirEntry>;
+    fn next(&mut self) -> Option<Self::Item> {
+        match *self {}
+    }
+}
+
+pub(crate) enum DirEntry {}
+
+impl DirEntry {
+    pub(crate) fn file_name(&self) -> &CStr {
+        match *self {}
+    }
+}
+
+pub(crate) fn hard_link(_original: &CStr, _link: &CStr) -> io::Result<()> {
+    Err(io::ErrorKind::Unsupported.into())
 }
 
 pub(crate) fn metadata(fd: BorrowedFd<'_>) -> io::Result<Metadata> {
     Ok(Metadata { size: sys_flen(fd)? as u64 })
 }
 
-pub(crate) fn open(path: &CStr, options: &fs::OpenOptions) -> io::Result<OwnedFd> {
+/// Arm semihosting-specific options of [`fs::OpenOptions`].
+#[derive(Clone, Debug)]
+pub(crate) struct OpenOptions {
+    pub(crate) open_mode: Option<OpenMode>,
+    pub(crate) text: bool,
+}
+
+impl OpenOptions {
+    pub(crate) const fn new() -> Self {
+        Self { open_mode: None, text: false }
+    }
+}
+
+/// Arm semihosting-specific extensions to [`fs::OpenOptions`].
+pub trait OpenOptionsExt: crate::sealed::Sealed {
+    /// Sets the raw [`OpenMode`] passed to [SYS_OPEN].
+    ///
+    /// When this is set, the other options (`read`, `write`, `append`, `truncate`,
+    /// `create`, and `create_new`) are ignored and the file is opened with the
+    /// given mode as is.
+    ///
+    /// [SYS_OPEN]: https://github.com/ARM-software/abi-aa/blob/2025Q4/semihosting/semihosting.rst#sys-open-0x01
+    fn open_mode(&mut self, mode: OpenMode) -> &mut Self;
+
+    /// Sets the option to open the file in text mode instead of binary mode.
+    ///
+    /// By default, files are opened in binary mode (e.g., `rb` and `wb` in
+    /// `fopen` modes). When this is set to `true`, the corresponding text mode
+    /// (e.g., `r` and `w`) is used, which causes, for example, the host's C
+    /// library to translate line endings on Windows hosts.
+    ///
+    /// This has no effect if [`open_mode`](Self::open_mode) is set.
+    fn text(&mut self, text: bool) -> &mut Self;
+}
+
+impl OpenOptionsExt for fs::OpenOptions {
+    fn open_mode(&mut self, mode: OpenMode) -> &mut Self {
+        self.platform.open_mode = Some(mode);
+        self
+    }
+    fn text(&mut self, text: bool) -> &mut Self {
+        self.platform.text = text;
+        self
+    }
+}
+
+/// Returns the file descriptor and whether the file was opened in append mode.
+fn open(path: &CStr, options: &fs::OpenOptions) -> io::Result<(OwnedFd, bool)> {
+    if let Some(mode) = options.platform.open_mode {
+        let append = matches!(
+            mode,
+            OpenMode::WRONLY_APPEND
+                | OpenMode::WRONLY_APPEND_BINARY
+                | OpenMode::RDWR_APPEND
+                | OpenMode::RDWR_APPEND_BINARY
+        );
+        return Ok((sys_open(path, mode)?, append));
+    }
     match (options.write, options.append) {
         (true, false) => {}
         (false, false) => {
-            if options.truncate || options.create {
+            if options.truncate || options.create || options.create_new {
                 return Err(io::Error::EINVAL);
             }
         }
@@ -38,9 +325,22 @@ pub(crate) fn open(path: &CStr, options: &fs::OpenOptions) -> io::Result<OwnedFd
             }
         }
     }
+    let (create, truncate) = if options.create_new {
+        // Arm semihosting has no equivalent of O_EXCL, so check that the file
+        // does not exist by opening it in read-only mode. This is racy, as
+        // documented in OpenOptions::create_new.
+        match sys_open(path, OpenMode::RDONLY_BINARY) {
+            Ok(_fd) => return Err(io::Error::from_raw_os_error(errno::EEXIST)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e),
+        }
+        // The file does not exist, so truncation has no effect.
+        (true, !options.append)
+    } else {
+        (options.create, options.truncate)
+    };
     // Refs: https://github.com/openocd-org/openocd/blob/HEAD/src/target/semihosting_common.c
-    let mode = match (options.read, options.write, options.append, options.create, options.truncate)
-    {
+    let mode = match (options.read, options.write, options.append, create, truncate) {
         (true, false, false, false, false) => OpenMode::RDONLY_BINARY,
         (true, true, false, false, false) => OpenMode::RDWR_BINARY,
         (false, true, false, true, true) => OpenMode::WRONLY_TRUNC_BINARY,
@@ -49,25 +349,19 @@ pub(crate) fn open(path: &CStr, options: &fs::OpenOptions) -> io::Result<OwnedFd
         (true, true, true, true, false) => OpenMode::RDWR_APPEND_BINARY,
         _ => return Err(io::Error::UNSUPPORTED_PLATFORM),
     };
-    sys_open(path, mode)
+    let mode = if options.platform.text { text_mode(mode) } else { mode };
+    Ok((sys_open(path, mode)?, options.append))
 }
 
-// TODO(arm_compat): Arm semihosting doesn't provide Large-file support (LFS).
-#[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
-pub(crate) fn seek(fd: BorrowedFd<'_>, pos: io::SeekFrom) -> io::Result<u64> {
-    let abs_pos = match pos {
-        io::SeekFrom::Start(pos) => pos,
-        io::SeekFrom::End(offset) => {
-            let len = sys_flen(fd)? as u64;
-            let pos = (len as i64).saturating_add(offset);
-            if pos.is_negative() {
-                return Err(io::Error::EINVAL);
-            }
-            pos as u64
-        } // io::SeekFrom::Current(_offset) => todo!(),
-    };
-    // sys_seek may succeed without this guard, but make the behavior consistent with other platforms.
-    let abs_pos = isize::try_from(abs_pos).map_err(|_| io::Error::EINVAL)?;
-    unsafe { sys_seek(fd, abs_pos as usize)? }
-    Ok(abs_pos as u64)
+/// Returns the text mode version of the given binary mode.
+fn text_mode(mode: OpenMode) -> OpenMode {
+    match mode {
+        OpenMode::RDONLY_BINARY => OpenMode::RDONLY,
+        OpenMode::RDWR_BINARY => OpenMode::RDWR,
+        OpenMode::WRONLY_TRUNC_BINARY => OpenMode::WRONLY_TRUNC,
+        OpenMode::RDWR_TRUNC_BINARY => OpenMode::RDWR_TRUNC,
+        OpenMode::WRONLY_APPEND_BINARY => OpenMode::WRONLY_APPEND,
+        OpenMode::RDWR_APPEND_BINARY => OpenMode::RDWR_APPEND,
+        mode => mode,
+    }
 }
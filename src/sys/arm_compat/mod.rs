@@ -15,7 +15,8 @@
 
 pub(crate) mod errno;
 #[cfg(feature = "fs")]
-pub(crate) mod fs;
+#[cfg_attr(docsrs, doc(cfg(feature = "fs")))]
+pub mod fs;
 #[cfg(feature = "stdio")]
 pub(crate) mod stdio;
 pub mod syscall;
@@ -33,6 +34,7 @@ use self::syscall::{
 use crate::{
     fd::{BorrowedFd, OwnedFd, RawFd},
     io,
+    sys::Errno,
     utils::slice_assume_init_mut,
 };
 
@@ -219,6 +221,18 @@ pub fn sys_errno() -> io::RawOsError {
     ret.errno()
 }
 
+/// Returns the value of the C library `errno` variable of the host as [`Errno`].
+///
+/// This is a typed wrapper around [`sys_errno`].
+///
+/// Note that whether `errno` is set or not, and to what value, is host-specific.
+#[doc(alias = "SYS_ERRNO")]
+#[inline]
+#[must_use]
+pub fn last_errno() -> Errno {
+    Errno::from_raw(sys_errno())
+}
+
 /// [SYS_EXIT (0x18)](https://github.com/ARM-software/abi-aa/blob/2025Q4/semihosting/semihosting.rst#sys-exit-0x18)
 // TODO(semver): change return type to !?
 pub fn sys_exit(reason: ExitReason) {
@@ -470,6 +484,12 @@ pub(crate) fn read_uninit<'a>(
     }
 }
 
+pub(crate) fn pread(fd: BorrowedFd<'_>, buf: &mut [u8], offset: usize) -> io::Result<usize> {
+    // TODO(arm_compat): seeking outside the current extent of the file is undefined.
+    unsafe { sys_seek(fd, offset)? }
+    read(fd, buf)
+}
+
 /// [SYS_READC (0x07)](https://github.com/ARM-software/abi-aa/blob/2025Q4/semihosting/semihosting.rst#sys-readc-0x07)
 pub fn sys_readc() -> u8 {
     // |                    | always          |                 |
@@ -561,6 +581,39 @@ pub fn sys_tickfreq() -> io::Result<usize> {
     if ret.signed() == -1 { Err(from_errno()) } else { Ok(ret.unsigned()) }
 }
 
+/// [SYS_TMPNAM (0x0D)](https://github.com/ARM-software/abi-aa/blob/2025Q4/semihosting/semihosting.rst#sys-tmpnam-0x0d)
+///
+/// Returns a temporary file name for the file identified by `id` in `buf`.
+/// The host returns the same name for the same `id`.
+///
+/// Note that the file name is only reserved by name, not created, so another
+/// process on the host may create a file with the same name at any time.
+#[deprecated = "tmpnam is deprecated as not secure on most host systems"]
+pub fn sys_tmpnam(id: u8, buf: &mut [u8]) -> io::Result<&CStr> {
+    let len = buf.len();
+    let mut block =
+        [ParamRegW::buf(buf), ParamRegW::unsigned(id as usize), ParamRegW::unsigned(len)];
+    // |                    | on success      | on failure      |                 |
+    // | ------------------ | --------------- | --------------- | --------------- |
+    // | RETURN REGISTER    | 0               | -1              |                 |
+    // | PARAMETER REGISTER | - (unmentioned) | - (unmentioned) | param_unchanged |
+    // | block / buf        | updated         | - (unmentioned) |                 |
+    #[allow(deprecated)]
+    let ret = unsafe {
+        syscall_param_unchanged(OperationNumber::SYS_TMPNAM, ParamRegW::block(&mut block))
+    };
+    if ret.unsigned() == 0 {
+        // The returned string is null-terminated.
+        match buf.iter().position(|&b| b == 0) {
+            Some(nul) => Ok(unsafe { CStr::from_bytes_with_nul_unchecked(&buf[..=nul]) }),
+            None => Err(io::ErrorKind::InvalidData.into()),
+        }
+    } else {
+        debug_assert_eq!(ret.signed(), -1);
+        Err(from_errno())
+    }
+}
+
 /// [SYS_TIME (0x11)](https://github.com/ARM-software/abi-aa/blob/2025Q4/semihosting/semihosting.rst#sys-time-0x11)
 #[allow(clippy::unnecessary_wraps)] // TODO(semver): change in next breaking release
 pub fn sys_time() -> io::Result<usize> {
@@ -617,6 +670,12 @@ pub(crate) fn write(fd: BorrowedFd<'_>, buf: &[u8]) -> io::Result<usize> {
     Ok(buf.len() - not_written)
 }
 
+pub(crate) fn pwrite(fd: BorrowedFd<'_>, buf: &[u8], offset: usize) -> io::Result<usize> {
+    // TODO(arm_compat): seeking outside the current extent of the file is undefined.
+    unsafe { sys_seek(fd, offset)? }
+    write(fd, buf)
+}
+
 /// [SYS_WRITEC (0x03)](https://github.com/ARM-software/abi-aa/blob/2025Q4/semihosting/semihosting.rst#sys-writec-0x03)
 pub fn sys_writec(character: u8) {
     // |                    | always          |                 |
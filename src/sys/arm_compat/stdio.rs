@@ -14,19 +14,59 @@ use crate::{
 // And, if the SH_EXT_STDOUT_STDERR semihosting extension is supported:
 // > If the special path name `:tt` is opened with an `fopen` mode requesting write access (`w`, `wb`, `w+`, or `w+b`), then this is a request to open `stdout`.
 // > If the special path name `:tt` is opened with a mode requesting append access (`a`, `ab`, `a+`, or `a+b`), then this is a request to open `stderr`.
-pub(crate) type StdioFd = OwnedFd;
-
-pub(crate) fn stdin() -> io::Result<StdioFd> {
+fn open_stdin() -> io::Result<OwnedFd> {
     sys_open(c!(":tt"), OpenMode::RDONLY)
 }
-pub(crate) fn stdout() -> io::Result<StdioFd> {
+fn open_stdout() -> io::Result<OwnedFd> {
     sys_open(c!(":tt"), OpenMode::WRONLY_TRUNC)
 }
-pub(crate) fn stderr() -> io::Result<StdioFd> {
-    // if failed, redirect to stdout
-    sys_open(c!(":tt"), OpenMode::WRONLY_APPEND).or_else(|_| stdout())
+fn open_stderr() -> io::Result<OwnedFd> {
+    sys_open(c!(":tt"), OpenMode::WRONLY_APPEND)
 }
 
+cfg_sel!({
+    // Opening `:tt` on every print is expensive (especially when using OpenOCD),
+    // so we open stdio streams once and reuse them until the end of the process.
+    #[cfg(any(target_has_atomic = "32", feature = "portable-atomic"))]
+    {
+        use crate::sys::once::OnceOwnedFd;
+
+        static STDIN: OnceOwnedFd = OnceOwnedFd::none();
+        static STDOUT: OnceOwnedFd = OnceOwnedFd::none();
+        static STDERR: OnceOwnedFd = OnceOwnedFd::none();
+
+        pub(crate) type StdioFd = BorrowedFd<'static>;
+
+        pub(crate) fn stdin() -> io::Result<StdioFd> {
+            STDIN.get_or_try_init(open_stdin)
+        }
+        pub(crate) fn stdout() -> io::Result<StdioFd> {
+            STDOUT.get_or_try_init(open_stdout)
+        }
+        pub(crate) fn stderr() -> io::Result<StdioFd> {
+            // if failed, redirect to stdout
+            // Cache the fallback too, so that hosts without SH_EXT_STDOUT_STDERR
+            // don't retry the failing open on every call.
+            STDERR.get_or_try_init(|| open_stderr().or_else(|_| open_stdout()))
+        }
+    }
+    #[cfg(else)]
+    {
+        pub(crate) type StdioFd = OwnedFd;
+
+        pub(crate) fn stdin() -> io::Result<StdioFd> {
+            open_stdin()
+        }
+        pub(crate) fn stdout() -> io::Result<StdioFd> {
+            open_stdout()
+        }
+        pub(crate) fn stderr() -> io::Result<StdioFd> {
+            // if failed, redirect to stdout
+            open_stderr().or_else(|_| stdout())
+        }
+    }
+});
+
 #[inline]
 pub(crate) fn should_close(_fd: &OwnedFd) -> bool {
     // In Arm semihosting, stdio streams are handled like normal fd.
@@ -47,9 +47,9 @@ impl OperationNumber {
     pub const SYS_SEEK: Self = Self(0x0A);
     /// [SYS_FLEN (0x0C)](https://github.com/ARM-software/abi-aa/blob/2025Q4/semihosting/semihosting.rst#sys-flen-0x0c)
     pub const SYS_FLEN: Self = Self(0x0C);
-    // /// [SYS_TMPNAM (0x0D)](https://github.com/ARM-software/abi-aa/blob/2025Q4/semihosting/semihosting.rst#sys-tmpnam-0x0d)
-    // #[deprecated = "tmpnam is deprecated as not secure on most host systems"]
-    //  pub const SYS_TMPNAM : Self = Self(0x0D);
+    /// [SYS_TMPNAM (0x0D)](https://github.com/ARM-software/abi-aa/blob/2025Q4/semihosting/semihosting.rst#sys-tmpnam-0x0d)
+    #[deprecated = "tmpnam is deprecated as not secure on most host systems"]
+    pub const SYS_TMPNAM: Self = Self(0x0D);
     /// [SYS_REMOVE (0x0E)](https://github.com/ARM-software/abi-aa/blob/2025Q4/semihosting/semihosting.rst#sys-remove-0x0e)
     pub const SYS_REMOVE: Self = Self(0x0E);
     /// [SYS_RENAME (0x0F)](https://github.com/ARM-software/abi-aa/blob/2025Q4/semihosting/semihosting.rst#sys-rename-0x0f)
@@ -1,17 +1,169 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use core::ffi::c_int;
+use core::{ffi::c_int, fmt};
 
 use crate::{
     io::{self, RawOsError},
     sys::arch::errno,
 };
 
+// Defines error number constants (both `pub(crate)` raw constants and
+// associated constants of `Errno`) and the table used by `Errno::name` and
+// `Errno::description`.
+//
+// Attributes at the beginning are applied to the `impl Errno` block.
+macro_rules! errno_table {
+    (
+        $(#[$impl_attr:meta])*
+        $($name:ident = $value:literal, $description:literal;)*
+    ) => {
+        $(
+            pub(crate) const $name: c_int = $value;
+        )*
+        pub(crate) const TABLE: &[(c_int, &str, &str)] = &[
+            $(($name, stringify!($name), $description),)*
+        ];
+        $(#[$impl_attr])*
+        impl crate::sys::Errno {
+            $(
+                #[doc = $description]
+                pub const $name: Self = Self::from_raw($name);
+            )*
+        }
+    };
+}
+
+/// An error number returned by semihosting calls.
+///
+/// The set of error numbers (and their values) is platform-specific:
+///
+/// - AArch64, Arm, RISC-V, LoongArch, Xtensa (openocd-semihosting): [SYS_ERRNO] says that
+///   the value is entirely host-specific, so only values that are the same on all host
+///   platforms officially supported by QEMU are defined as associated constants.
+/// - MIPS32, MIPS64: values defined in the appendix of the UHI reference manual.
+///
+/// [SYS_ERRNO]: https://github.com/ARM-software/abi-aa/blob/2025Q4/semihosting/semihosting.rst#sys-errno-0x13
+///
+/// # Examples
+///
+/// ```no_run
+/// use semihosting::{fs, io, sys::Errno};
+///
+/// match fs::remove_file(c"a.txt") {
+///     Err(e) if Errno::from_io_error(&e) == Some(Errno::ENOENT) => {}
+///     res => res?,
+/// }
+/// # Ok::<(), io::Error>(())
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Errno(c_int);
+
+impl Errno {
+    /// Creates an `Errno` from a raw error number.
+    #[inline]
+    #[must_use]
+    pub const fn from_raw(errno: c_int) -> Self {
+        Self(errno)
+    }
+
+    /// Returns the raw error number.
+    #[inline]
+    #[must_use]
+    pub const fn raw(self) -> c_int {
+        self.0
+    }
+
+    /// Returns the error number that `err` represents (if any).
+    ///
+    /// If `err` was created from an OS error code, this returns that code.
+    /// Otherwise, this returns the error number corresponding to
+    /// [`err.kind()`](io::Error::kind) (see [`Errno::from_error_kind`]).
+    #[must_use]
+    pub fn from_io_error(err: &io::Error) -> Option<Self> {
+        match err.raw_os_error() {
+            Some(code) => Some(Self(code as c_int)),
+            None => Self::from_error_kind(err.kind()),
+        }
+    }
+
+    /// Returns an error number that corresponds to `kind` on the current
+    /// platform (if any).
+    ///
+    /// If multiple error numbers correspond to `kind` (e.g., both `EPERM` and
+    /// `EACCES` are [`PermissionDenied`](io::ErrorKind::PermissionDenied)),
+    /// the one with the smallest value is returned.
+    #[must_use]
+    pub fn from_error_kind(kind: io::ErrorKind) -> Option<Self> {
+        if kind == io::ErrorKind::Other {
+            return None;
+        }
+        errno::TABLE.iter().map(|&(errno, ..)| Self(errno)).find(|errno| errno.kind() == kind)
+    }
+
+    /// Returns the corresponding [`io::ErrorKind`] for this error number.
+    #[inline]
+    #[must_use]
+    pub fn kind(self) -> io::ErrorKind {
+        decode_error_kind(self.0 as RawOsError)
+    }
+
+    /// Returns the name of this error number (e.g., `"ENOENT"`), or `None`
+    /// if this is not a known error number on the current platform.
+    #[must_use]
+    pub fn name(self) -> Option<&'static str> {
+        errno::TABLE.iter().find(|&&(errno, ..)| errno == self.0).map(|&(_, name, _)| name)
+    }
+
+    /// Returns the description of this error number (e.g., `"No such file or directory"`),
+    /// like `strerror`, or `None` if this is not a known error number on the
+    /// current platform.
+    #[must_use]
+    pub fn description(self) -> Option<&'static str> {
+        errno::TABLE.iter().find(|&&(errno, ..)| errno == self.0).map(|&(.., desc)| desc)
+    }
+}
+
+impl From<Errno> for io::Error {
+    #[inline]
+    fn from(errno: Errno) -> Self {
+        Self::from_raw_os_error(errno.0 as RawOsError)
+    }
+}
+impl From<Errno> for io::ErrorKind {
+    #[inline]
+    fn from(errno: Errno) -> Self {
+        errno.kind()
+    }
+}
+
+impl fmt::Debug for Errno {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.name() {
+            Some(name) => f.write_str(name),
+            None => f.debug_tuple("Errno").field(&self.0).finish(),
+        }
+    }
+}
+impl fmt::Display for Errno {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.description() {
+            Some(description) => f.write_str(description),
+            None => write!(f, "Unknown error {}", self.0),
+        }
+    }
+}
+
 #[inline]
 pub(crate) fn is_interrupted(errno: RawOsError) -> bool {
     errno as c_int == errno::EINTR
 }
 
+/// Returns the description of the given error number, like `strerror`.
+pub(crate) fn error_string(errno: RawOsError) -> &'static str {
+    Errno(errno as c_int).description().unwrap_or("Unknown error")
+}
+
 // Adapted from https://github.com/rust-lang/rust/blob/1.92.0/library/std/src/sys/pal/unix/mod.rs#L235.
 pub(crate) fn decode_error_kind(errno: RawOsError) -> io::ErrorKind {
     #[allow(clippy::enum_glob_use)]
@@ -149,3 +301,61 @@ pub(crate) fn decode_error_kind(errno: RawOsError) -> io::ErrorKind {
         _ => Other,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Errno, decode_error_kind, errno};
+    use crate::io;
+
+    #[test]
+    fn table() {
+        assert!(!errno::TABLE.is_empty());
+        for &(raw, name, description) in errno::TABLE {
+            let e = Errno::from_raw(raw);
+            assert_eq!(e.raw(), raw);
+            assert!(name.starts_with('E'), "{name}");
+            assert!(!description.is_empty(), "{name}");
+            assert_eq!(e.description(), Some(description));
+            // Aliases (e.g., EWOULDBLOCK on MIPS) have the same value as the
+            // preceding entry, so the name of the first entry is returned.
+            let first = errno::TABLE.iter().find(|&&(r, ..)| r == raw).unwrap();
+            assert_eq!(e.name(), Some(first.1));
+            assert_eq!(e.kind(), decode_error_kind(raw));
+            assert_eq!(io::ErrorKind::from(e), e.kind());
+
+            let err = io::Error::from(e);
+            assert_eq!(err.raw_os_error(), Some(raw));
+            assert_eq!(err.kind(), e.kind());
+            assert_eq!(Errno::from_io_error(&err), Some(e));
+
+            if e.kind() != io::ErrorKind::Other {
+                let canonical = Errno::from_error_kind(e.kind()).unwrap();
+                assert_eq!(canonical.kind(), e.kind(), "{name}");
+                assert!(canonical <= e, "{name}");
+                assert_eq!(Errno::from_io_error(&e.kind().into()), Some(canonical));
+            }
+        }
+    }
+
+    #[test]
+    fn known() {
+        assert_eq!(Errno::ENOENT.name(), Some("ENOENT"));
+        assert_eq!(Errno::ENOENT.description(), Some("No such file or directory"));
+        assert_eq!(Errno::ENOENT.kind(), io::ErrorKind::NotFound);
+        assert_eq!(Errno::from_error_kind(io::ErrorKind::NotFound), Some(Errno::ENOENT));
+        assert_eq!(Errno::from_error_kind(io::ErrorKind::PermissionDenied), Some(Errno::EPERM));
+        assert_eq!(Errno::from_error_kind(io::ErrorKind::InvalidInput), Some(Errno::EINVAL));
+        assert_eq!(Errno::from_error_kind(io::ErrorKind::Other), None);
+        assert_eq!(Errno::from_error_kind(io::ErrorKind::UnexpectedEof), None);
+        assert_eq!(Errno::from_io_error(&io::Error::READ_EXACT_EOF), None);
+    }
+
+    #[test]
+    fn unknown() {
+        let e = Errno::from_raw(9999);
+        assert_eq!(e.name(), None);
+        assert_eq!(e.description(), None);
+        assert_eq!(e.kind(), io::ErrorKind::Other);
+        assert_eq!(Errno::from_raw(0).name(), None);
+    }
+}
@@ -2,46 +2,55 @@
 
 // Values are defined in the appendix of Reference Manual.
 
-// TODO(sys): should we expose this as public API of `sys` module?
-
 #![allow(dead_code)]
 
 use core::ffi::c_int;
 
-pub(crate) const EPERM: c_int = 1; // link, unlink
-pub(crate) const ENOENT: c_int = 2; // link, unlink, open
-pub(crate) const EINTR: c_int = 4; // open, close, read, write
-pub(crate) const EIO: c_int = 5; // pwrite, open, close, fstat, pread, read, write
-pub(crate) const ENXIO: c_int = 6; // open, pread, pwrite, read, write
-pub(crate) const EBADF: c_int = 9; // pwrite, pread, close, lseek, fstat, read, write
-pub(crate) const EAGAIN: c_int = 11; // pread, pwrite, read, write
-pub(crate) const EWOULDBLOCK: c_int = 11; // pread, pwrite
-pub(crate) const ENOMEM: c_int = 12; // open, pread, read
-pub(crate) const EACCES: c_int = 13; // pwrite, link, unlink, open, write
-pub(crate) const EBUSY: c_int = 16; // unlink,
-pub(crate) const EEXIST: c_int = 17; // link, open,
-pub(crate) const EXDEV: c_int = 18; // link
-pub(crate) const ENOTDIR: c_int = 20; // link, unlink, open
-pub(crate) const EISDIR: c_int = 21; // open, pread, read
-pub(crate) const EINVAL: c_int = 22; // lseek, pread, pwrite, open, read
-pub(crate) const ENFILE: c_int = 23; // open
-pub(crate) const EMFILE: c_int = 24; // open
-pub(crate) const ETXTBSY: c_int = 26; // unlink, open
-pub(crate) const EFBIG: c_int = 27; // pwrite, read, write
-pub(crate) const ENOSPC: c_int = 28; // pwrite, link, open, write
-pub(crate) const ESPIPE: c_int = 29; // lseek, pread, pwrite, read
-pub(crate) const EROFS: c_int = 30; // link, unlink, open
-pub(crate) const EMLINK: c_int = 31; // link,
-pub(crate) const EPIPE: c_int = 32; // pwrite, write, write
-pub(crate) const ERANGE: c_int = 34; // pwrite, write
-pub(crate) const ENOSR: c_int = 63; // open
-pub(crate) const EBADMSG: c_int = 77; // pread, read
-pub(crate) const ENAMETOOLONG: c_int = 91; // link, unlink, open
-pub(crate) const ELOOP: c_int = 92; // open, link, unlink
-pub(crate) const ECONNRESET: c_int = 104; // pread, pwrite, read
-pub(crate) const ENOBUFS: c_int = 105; // pread, pwrite, read, write
-pub(crate) const ENETUNREACH: c_int = 114; // pwrite, write
-pub(crate) const ENETDOWN: c_int = 115; // pwrite, write
-pub(crate) const ETIMEDOUT: c_int = 116; // pread, read
-pub(crate) const ENOTCONN: c_int = 128; // pread, read
-pub(crate) const EOVERFLOW: c_int = 139; // open, lseek, fstat, pread, read
+errno_table! {
+    // This module is also compiled when building documentation for other
+    // platforms, but associated constants of `Errno` should be the current
+    // platform's ones.
+    #[cfg(any(
+        target_arch = "mips",
+        target_arch = "mips32r6",
+        target_arch = "mips64",
+        target_arch = "mips64r6",
+    ))]
+    EPERM = 1, "Operation not permitted"; // link, unlink
+    ENOENT = 2, "No such file or directory"; // link, unlink, open
+    EINTR = 4, "Interrupted system call"; // open, close, read, write
+    EIO = 5, "Input/output error"; // pwrite, open, close, fstat, pread, read, write
+    ENXIO = 6, "No such device or address"; // open, pread, pwrite, read, write
+    EBADF = 9, "Bad file descriptor"; // pwrite, pread, close, lseek, fstat, read, write
+    EAGAIN = 11, "Resource temporarily unavailable"; // pread, pwrite, read, write
+    EWOULDBLOCK = 11, "Resource temporarily unavailable"; // pread, pwrite
+    ENOMEM = 12, "Cannot allocate memory"; // open, pread, read
+    EACCES = 13, "Permission denied"; // pwrite, link, unlink, open, write
+    EBUSY = 16, "Device or resource busy"; // unlink,
+    EEXIST = 17, "File exists"; // link, open,
+    EXDEV = 18, "Invalid cross-device link"; // link
+    ENOTDIR = 20, "Not a directory"; // link, unlink, open
+    EISDIR = 21, "Is a directory"; // open, pread, read
+    EINVAL = 22, "Invalid argument"; // lseek, pread, pwrite, open, read
+    ENFILE = 23, "Too many open files in system"; // open
+    EMFILE = 24, "Too many open files"; // open
+    ETXTBSY = 26, "Text file busy"; // unlink, open
+    EFBIG = 27, "File too large"; // pwrite, read, write
+    ENOSPC = 28, "No space left on device"; // pwrite, link, open, write
+    ESPIPE = 29, "Illegal seek"; // lseek, pread, pwrite, read
+    EROFS = 30, "Read-only file system"; // link, unlink, open
+    EMLINK = 31, "Too many links"; // link,
+    EPIPE = 32, "Broken pipe"; // pwrite, write, write
+    ERANGE = 34, "Numerical result out of range"; // pwrite, write
+    ENOSR = 63, "Out of streams resources"; // open
+    EBADMSG = 77, "Bad message"; // pread, read
+    ENAMETOOLONG = 91, "File name too long"; // link, unlink, open
+    ELOOP = 92, "Too many levels of symbolic links"; // open, link, unlink
+    ECONNRESET = 104, "Connection reset by peer"; // pread, pwrite, read
+    ENOBUFS = 105, "No buffer space available"; // pread, pwrite, read, write
+    ENETUNREACH = 114, "Network is unreachable"; // pwrite, write
+    ENETDOWN = 115, "Network is down"; // pwrite, write
+    ETIMEDOUT = 116, "Connection timed out"; // pread, read
+    ENOTCONN = 128, "Transport endpoint is not connected"; // pread, read
+    EOVERFLOW = 139, "Value too large for defined data type"; // open, lseek, fstat, pread, read
+}
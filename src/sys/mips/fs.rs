@@ -1,30 +1,195 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+//! MIPS UHI-specific extensions to primitives in the [`semihosting::fs`](crate::fs) module.
+
 use core::ffi::CStr;
 
 use super::{
-    O_APPEND, O_CREAT, O_EXCL, O_RDONLY, O_RDWR, O_TRUNC, O_WRONLY, SeekWhence::SEEK_SET,
-    mips_fstat, mips_lseek, mips_open,
+    O_APPEND, O_CREAT, O_EXCL, O_RDONLY, O_RDWR, O_TRUNC, O_WRONLY, S_IFCHR, S_IFDIR, S_IFLNK,
+    S_IFMT, S_IFREG,
+    SeekWhence::{SEEK_CUR, SEEK_SET},
+    mips_findclose, mips_findfirst, mips_findnext, mips_fstat, mips_link, mips_lseek, mips_open,
+    mips_unlink,
+};
+pub(crate) use super::{
+    mips_fstat as metadata, mips_link as hard_link, mips_unlink as unlink, uhi_stat as Metadata,
 };
-pub(crate) use super::{mips_fstat as metadata, mips_unlink as unlink, uhi_stat as Metadata};
 use crate::{
-    fd::{BorrowedFd, OwnedFd},
-    fs, io,
+    fd::{AsFd as _, BorrowedFd, OwnedFd},
+    fs, io, sys,
 };
 
+/// MIPS UHI-specific options of [`fs::OpenOptions`].
+///
+/// There are currently no such options.
+#[derive(Clone, Debug)]
+pub(crate) struct OpenOptions {}
+
+impl OpenOptions {
+    pub(crate) const fn new() -> Self {
+        Self {}
+    }
+}
+
+pub(crate) struct File(OwnedFd);
+
+impl File {
+    #[inline]
+    pub(crate) fn open(path: &CStr, options: &fs::OpenOptions) -> io::Result<Self> {
+        open(path, options).map(Self)
+    }
+    #[inline]
+    pub(crate) fn from_fd(fd: OwnedFd) -> Self {
+        Self(fd)
+    }
+    #[inline]
+    pub(crate) fn into_fd(self) -> OwnedFd {
+        self.0
+    }
+    #[inline]
+    pub(crate) fn as_fd(&self) -> BorrowedFd<'_> {
+        self.0.as_fd()
+    }
+    #[inline]
+    pub(crate) fn read(&self, buf: &mut [u8]) -> io::Result<usize> {
+        sys::read(self.as_fd(), buf)
+    }
+    #[inline]
+    pub(crate) fn write(&self, buf: &[u8]) -> io::Result<usize> {
+        sys::write(self.as_fd(), buf)
+    }
+    #[inline]
+    pub(crate) fn read_at(&self, buf: &mut [u8], offset: u64) -> io::Result<usize> {
+        sys::pread(self.as_fd(), buf, offset)
+    }
+    #[inline]
+    pub(crate) fn write_at(&self, buf: &[u8], offset: u64) -> io::Result<usize> {
+        sys::pwrite(self.as_fd(), buf, offset)
+    }
+    #[inline]
+    pub(crate) fn seek(&self, pos: io::SeekFrom) -> io::Result<u64> {
+        seek(self.as_fd(), pos)
+    }
+    #[inline]
+    pub(crate) fn stream_position(&self) -> io::Result<u64> {
+        seek(self.as_fd(), io::SeekFrom::Current(0))
+    }
+}
+
 impl Metadata {
     #[inline]
     pub(crate) fn size(&self) -> u64 {
         self.st_size
     }
+    #[inline]
+    pub(crate) fn file_type(&self) -> FileType {
+        FileType { mode: self.st_mode }
+    }
+    #[inline]
+    pub(crate) fn permissions(&self) -> Permissions {
+        Permissions { mode: self.st_mode }
+    }
+    #[cfg(feature = "time")]
+    pub(crate) fn modified(&self) -> io::Result<sys::time::SystemTime> {
+        sys::time::SystemTime::from_unix_secs(self.st_mtime)
+    }
+    #[cfg(feature = "time")]
+    pub(crate) fn accessed(&self) -> io::Result<sys::time::SystemTime> {
+        sys::time::SystemTime::from_unix_secs(self.st_atime)
+    }
+    #[cfg(feature = "time")]
+    #[allow(clippy::unused_self)]
+    pub(crate) fn created(&self) -> io::Result<sys::time::SystemTime> {
+        // st_ctime is the time of the last status change, not the creation time.
+        Err(io::ErrorKind::Unsupported.into())
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct FileType {
+    mode: u32,
+}
+
+impl FileType {
+    #[allow(clippy::cast_sign_loss)]
+    #[inline]
+    fn is(self, mode: i32) -> bool {
+        self.mode & S_IFMT as u32 == mode as u32
+    }
+    #[inline]
+    pub(crate) fn is_dir(self) -> bool {
+        self.is(S_IFDIR)
+    }
+    #[inline]
+    pub(crate) fn is_file(self) -> bool {
+        self.is(S_IFREG)
+    }
+    #[inline]
+    pub(crate) fn is_symlink(self) -> bool {
+        self.is(S_IFLNK)
+    }
+    #[inline]
+    pub(crate) fn is_char_device(self) -> bool {
+        self.is(S_IFCHR)
+    }
+}
+
+#[derive(Clone, PartialEq, Eq)]
+pub(crate) struct Permissions {
+    mode: u32,
+}
+
+impl Permissions {
+    #[inline]
+    pub(crate) fn readonly(&self) -> bool {
+        // check if any class (owner, group, others) has write permission
+        self.mode & 0o222 == 0
+    }
+}
+
+/// MIPS UHI-specific extensions to [`fs::OpenOptions`].
+pub trait OpenOptionsExt: crate::sealed::Sealed {
+    /// Sets the mode bits that a new file will be created with.
+    ///
+    /// If a new file is created as part of an `OpenOptions::open` call then this
+    /// specified `mode` will be used as the permission bits for the new file.
+    /// If no `mode` is set, the default of `0o666` will be used.
+    ///
+    /// See [`std::os::unix::fs::OpenOptionsExt::mode` documentation][std] for details.
+    ///
+    /// [std]: https://doc.rust-lang.org/std/os/unix/fs/trait.OpenOptionsExt.html#tymethod.mode
+    fn mode(&mut self, mode: u32) -> &mut Self;
+
+    /// Passes custom flags to the `flags` argument of UHI_open.
+    ///
+    /// The bits that define the access mode are masked out with `O_RDONLY | O_WRONLY | O_RDWR`,
+    /// to ensure they do not interfere with the access mode set by Rust's options.
+    ///
+    /// Custom flags can only set flags, not remove flags set by Rust's options.
+    ///
+    /// See [`std::os::unix::fs::OpenOptionsExt::custom_flags` documentation][std] for details.
+    ///
+    /// [std]: https://doc.rust-lang.org/std/os/unix/fs/trait.OpenOptionsExt.html#tymethod.custom_flags
+    fn custom_flags(&mut self, flags: i32) -> &mut Self;
+}
+
+impl OpenOptionsExt for fs::OpenOptions {
+    fn mode(&mut self, mode: u32) -> &mut Self {
+        self.mode = mode;
+        self
+    }
+    fn custom_flags(&mut self, flags: i32) -> &mut Self {
+        self.custom_flags = flags;
+        self
+    }
 }
 
 #[allow(clippy::cast_possible_wrap)]
-pub(crate) fn open(path: &CStr, options: &fs::OpenOptions) -> io::Result<OwnedFd> {
+fn open(path: &CStr, options: &fs::OpenOptions) -> io::Result<OwnedFd> {
     match (options.write, options.append) {
         (true, false) => {}
         (false, false) => {
-            if options.truncate || options.create {
+            if options.truncate || options.create || options.create_new {
                 return Err(io::Error::EINVAL);
             }
         }
@@ -49,12 +214,13 @@ pub(crate) fn open(path: &CStr, options: &fs::OpenOptions) -> io::Result<OwnedFd
         (true, true, false) => O_CREAT | O_TRUNC,
         (_, _, true) => O_CREAT | O_EXCL,
     };
-    mips_open(path, access_mode | creation_mode, options.mode as i32)
+    let custom_flags = options.custom_flags & !(O_RDONLY | O_WRONLY | O_RDWR);
+    mips_open(path, access_mode | creation_mode | custom_flags, options.mode as i32)
 }
 
 // TODO(mips): UHI doesn't provide Large-file support (LFS).
 #[allow(clippy::cast_possible_wrap)]
-pub(crate) fn seek(fd: BorrowedFd<'_>, pos: io::SeekFrom) -> io::Result<u64> {
+fn seek(fd: BorrowedFd<'_>, pos: io::SeekFrom) -> io::Result<u64> {
     let (whence, offset) = match pos {
         io::SeekFrom::Start(pos) => (SEEK_SET, pos as i64),
         io::SeekFrom::End(offset) => {
@@ -65,13 +231,167 @@ pub(crate) fn seek(fd: BorrowedFd<'_>, pos: io::SeekFrom) -> io::Result<u64> {
                 return Err(io::Error::EINVAL);
             }
             (SEEK_SET, pos)
-        } // io::SeekFrom::Current(offset) => (SEEK_CUR, offset),
+        }
+        io::SeekFrom::Current(offset) => (SEEK_CUR, offset),
     };
     // mips_lseek will fail even without this guard, but errno will not be set.
     let offset = isize::try_from(offset).map_err(|_| io::Error::EINVAL)?;
     Ok(unsafe { mips_lseek(fd, offset, whence)? as u64 })
 }
 
-pub(crate) fn rename(_from: &CStr, _to: &CStr) -> io::Result<()> {
+/// The size of the buffer used to hold the name of a directory entry, including the trailing nul.
+const NAME_BUF_SIZE: usize = 256;
+
+pub(crate) struct ReadDir {
+    handle: usize,
+    // The entry returned by UHI_findfirst, which has not been yielded yet.
+    first: Option<DirEntry>,
+    done: bool,
+}
+
+pub(crate) fn read_dir(path: &CStr) -> io::Result<ReadDir> {
+    let mut name = [0; NAME_BUF_SIZE];
+    let (handle, len) = {
+        let (handle, first) = mips_findfirst(path, &mut name)?;
+        (handle, first.to_bytes_with_nul().len())
+    };
+    let first = DirEntry { name, len };
+    // An empty name means that the directory has no entries (see is_end_of_dir).
+    let done = first.file_name().is_empty();
+    Ok(ReadDir { handle, first: if done { None } else { Some(first) }, done })
+}
+
+impl Iterator for ReadDir {
+    type Item = io::Result<DirEntry>;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let entry = match self.first.take() {
+                Some(entry) => entry,
+                None => {
+                    if self.done {
+                        return None;
+                    }
+                    let mut name = [0; NAME_BUF_SIZE];
+                    let res = mips_findnext(self.handle, &mut name);
+                    if is_end_of_dir(&res) {
+                        self.done = true;
+                        return None;
+                    }
+                    match res {
+                        Ok(next) => {
+                            let len = next.to_bytes_with_nul().len();
+                            DirEntry { name, len }
+                        }
+                        Err(e) => {
+                            self.done = true;
+                            return Some(Err(e));
+                        }
+                    }
+                }
+            };
+            if !is_dot_or_dot_dot(entry.file_name()) {
+                return Some(Ok(entry));
+            }
+        }
+    }
+}
+
+/// Returns `true` if `res`, the result of UHI_findnext, indicates the end of
+/// the directory.
+///
+/// UHI (see the "Unified Hosting Interface. MD01069 Reference Manual") reports
+/// failures of UHI_findnext as -1 with the errno in $3, but doesn't define a
+/// dedicated errno for the end of the directory. So all of the following are
+/// treated as the end of the directory: failing with ENOENT, failing without
+/// setting an errno (errno 0), and succeeding with an empty name.
+fn is_end_of_dir(res: &io::Result<&CStr>) -> bool {
+    match res {
+        Ok(name) => name.is_empty(),
+        Err(e) => e.kind() == io::ErrorKind::NotFound || e.raw_os_error() == Some(0),
+    }
+}
+
+/// Returns `true` if `name` is `.` or `..`, which are skipped as std does.
+fn is_dot_or_dot_dot(name: &CStr) -> bool {
+    matches!(name.to_bytes(), b"." | b"..")
+}
+
+impl Drop for ReadDir {
+    fn drop(&mut self) {
+        // SAFETY: the handle was returned by mips_findfirst and is closed only here.
+        let _ = unsafe { mips_findclose(self.handle) };
+    }
+}
+
+pub(crate) struct DirEntry {
+    name: [u8; NAME_BUF_SIZE],
+    // Length of the name, including the trailing nul.
+    len: usize,
+}
+
+impl DirEntry {
+    #[inline]
+    pub(crate) fn file_name(&self) -> &CStr {
+        // SAFETY: name[..len] is a null-terminated string without interior nul
+        // written by UHI_findfirst/UHI_findnext.
+        unsafe { CStr::from_bytes_with_nul_unchecked(&self.name[..self.len]) }
+    }
+}
+
+pub(crate) fn tmpnam(_id: u8, _buf: &mut [u8]) -> io::Result<&CStr> {
     Err(io::ErrorKind::Unsupported.into())
 }
+
+pub(crate) fn copy(from: &CStr, to: &CStr) -> io::Result<u64> {
+    let mut reader = fs::File::open(from)?;
+    let mode = mips_fstat(reader.as_fd())?.st_mode;
+    if !(FileType { mode }).is_file() {
+        return Err(io::Error::COPY_SOURCE_NOT_FILE);
+    }
+    // UHI doesn't provide fchmod, so the permission bits are only applied
+    // when the destination file is created.
+    io::file_copy(&mut reader, || {
+        fs::OpenOptions::new().write(true).create(true).truncate(true).mode(mode & 0o7777).open(to)
+    })
+}
+
+// UHI doesn't provide rename, so emulate it with link + unlink.
+// Unlike the real rename, this is not atomic (the file is accessible under
+// both names between the two calls), doesn't work for directories, and fails
+// if `to` already exists: replacing `to` would require removing it first, and
+// the file would be lost if the subsequent link failed.
+pub(crate) fn rename(from: &CStr, to: &CStr) -> io::Result<()> {
+    mips_link(from, to)?;
+    if let Err(e) = mips_unlink(from) {
+        // Undo the link so that the file is not left under both names.
+        let _ = mips_unlink(to);
+        return Err(e);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_dot_or_dot_dot, is_end_of_dir};
+    use crate::io;
+
+    #[test]
+    fn end_of_dir() {
+        assert!(is_end_of_dir(&Ok(c!(""))));
+        assert!(!is_end_of_dir(&Ok(c!("a"))));
+        assert!(!is_end_of_dir(&Ok(c!("."))));
+        assert!(is_end_of_dir(&Err(io::ErrorKind::NotFound.into())));
+        assert!(is_end_of_dir(&Err(io::Error::from_raw_os_error(0))));
+        assert!(!is_end_of_dir(&Err(io::ErrorKind::PermissionDenied.into())));
+    }
+
+    #[test]
+    fn dot_or_dot_dot() {
+        assert!(is_dot_or_dot_dot(c!(".")));
+        assert!(is_dot_or_dot_dot(c!("..")));
+        assert!(!is_dot_or_dot_dot(c!("")));
+        assert!(!is_dot_or_dot_dot(c!("...")));
+        assert!(!is_dot_or_dot_dot(c!(".a")));
+        assert!(!is_dot_or_dot_dot(c!("a")));
+    }
+}
@@ -10,7 +10,8 @@
 
 pub(crate) mod errno;
 #[cfg(feature = "fs")]
-pub(crate) mod fs;
+#[cfg_attr(docsrs, doc(cfg(feature = "fs")))]
+pub mod fs;
 #[cfg(feature = "stdio")]
 pub(crate) mod stdio;
 pub mod syscall;
@@ -19,8 +20,8 @@ use core::{ffi::CStr, mem, mem::MaybeUninit};
 
 use self::syscall::{
     OperationCode, ParamRegR, ParamRegW, RetReg, syscall0, syscall1_noreturn_readonly,
-    syscall1_readonly, syscall2, syscall2_readonly, syscall3, syscall3_readonly, syscall4,
-    syscall4_readonly,
+    syscall1_readonly, syscall2, syscall2_readonly, syscall3, syscall3_readonly,
+    syscall3_readonly_arg1, syscall4, syscall4_readonly,
 };
 use crate::{
     fd::{BorrowedFd, OwnedFd, RawFd},
@@ -38,6 +39,15 @@ mod consts {
     pub const O_TRUNC: i32 = 0x400;
     pub const O_EXCL: i32 = 0x800;
 
+    pub const S_IFMT: i32 = 0o170_000;
+    pub const S_IFSOCK: i32 = 0o140_000;
+    pub const S_IFLNK: i32 = 0o120_000;
+    pub const S_IFREG: i32 = 0o100_000;
+    pub const S_IFBLK: i32 = 0o60000;
+    pub const S_IFDIR: i32 = 0o40000;
+    pub const S_IFCHR: i32 = 0o20000;
+    pub const S_IFIFO: i32 = 0o10000;
+
     pub const S_IXOTH: i32 = 0o1;
     pub const S_IWOTH: i32 = 0o2;
     pub const S_IROTH: i32 = 0o4;
@@ -270,6 +280,86 @@ pub fn mips_plog(msg: &CStr) -> io::Result<usize> {
 
 // TODO(mips): UHI_ASSERT
 
+/// UHI_findfirst
+///
+/// Starts enumerating the entries of the directory `path`, and writes the
+/// null-terminated name of the first entry to `buf`.
+///
+/// Returns the handle to pass to [`mips_findnext`] and [`mips_findclose`], and
+/// the name of the first entry.
+pub fn mips_findfirst<'a>(path: &CStr, buf: &'a mut [u8]) -> io::Result<(usize, &'a CStr)> {
+    let len = buf.len();
+    let (res, errno) = unsafe {
+        syscall3_readonly_arg1(
+            OperationCode::UHI_FINDFIRST,
+            ParamRegR::c_str(path),
+            ParamRegW::buf(buf),
+            ParamRegW::unsigned(len),
+        )
+    };
+    if res.signed() == -1 {
+        return Err(from_errno(errno));
+    }
+    let handle = res.unsigned();
+    match c_str_in(buf) {
+        Ok(name) => Ok((handle, name)),
+        Err(e) => {
+            // Do not leak the handle, as the caller cannot close it.
+            // SAFETY: the handle was just returned by UHI_findfirst.
+            let _ = unsafe { mips_findclose(handle) };
+            Err(e)
+        }
+    }
+}
+
+/// UHI_findnext
+///
+/// Writes the null-terminated name of the next entry of the directory
+/// associated with `handle` to `buf`.
+///
+/// Fails if there are no more entries.
+pub fn mips_findnext(handle: usize, buf: &mut [u8]) -> io::Result<&CStr> {
+    let len = buf.len();
+    let (res, errno) = unsafe {
+        syscall3(
+            OperationCode::UHI_FINDNEXT,
+            ParamRegW::unsigned(handle),
+            ParamRegW::buf(buf),
+            ParamRegW::unsigned(len),
+        )
+    };
+    if res.unsigned() == 0 {
+        c_str_in(buf)
+    } else {
+        debug_assert_eq!(res.signed(), -1);
+        Err(from_errno(errno))
+    }
+}
+
+/// UHI_findclose
+///
+/// # Safety
+///
+/// `handle` must be a handle returned by [`mips_findfirst`] that has not been closed yet.
+pub unsafe fn mips_findclose(handle: usize) -> io::Result<()> {
+    let (res, errno) =
+        unsafe { syscall1_readonly(OperationCode::UHI_FINDCLOSE, ParamRegR::unsigned(handle)) };
+    if res.unsigned() == 0 {
+        Ok(())
+    } else {
+        debug_assert_eq!(res.signed(), -1);
+        Err(from_errno(errno))
+    }
+}
+
+/// Returns the null-terminated string written to `buf` by the host.
+fn c_str_in(buf: &[u8]) -> io::Result<&CStr> {
+    match buf.iter().position(|&b| b == 0) {
+        Some(nul) => Ok(unsafe { CStr::from_bytes_with_nul_unchecked(&buf[..=nul]) }),
+        None => Err(io::ErrorKind::InvalidData.into()),
+    }
+}
+
 /// UHI_pread
 pub fn mips_pread(fd: BorrowedFd<'_>, buf: &mut [u8], offset: usize) -> io::Result<usize> {
     let len = buf.len();
@@ -278,6 +368,7 @@ pub fn mips_pread(fd: BorrowedFd<'_>, buf: &mut [u8], offset: usize) -> io::Resu
         unsafe { core::slice::from_raw_parts_mut(buf.as_mut_ptr().cast::<MaybeUninit<u8>>(), len) };
     Ok(mips_pread_uninit(fd, buf, offset)?.0.len())
 }
+pub(crate) use self::mips_pread as pread;
 fn mips_pread_uninit<'a>(
     fd: BorrowedFd<'_>,
     buf: &'a mut [MaybeUninit<u8>],
@@ -320,6 +411,7 @@ pub fn mips_pwrite(fd: BorrowedFd<'_>, buf: &[u8], offset: usize) -> io::Result<
         Ok(res.unsigned())
     }
 }
+pub(crate) use self::mips_pwrite as pwrite;
 
 /// UHI_link
 pub fn mips_link(old: &CStr, new: &CStr) -> io::Result<()> {
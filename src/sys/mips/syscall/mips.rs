@@ -176,6 +176,36 @@ pub unsafe fn syscall3(
     }
 }
 
+/// Raw semihosting call with 3 parameters, where the first parameter will be
+/// read (but not modified) by the host and the others will be read + modified
+/// by the host.
+#[inline]
+pub(crate) unsafe fn syscall3_readonly_arg1(
+    op: OperationCode,
+    arg1: ParamRegR<'_>,
+    arg2: ParamRegW<'_>,
+    arg3: ParamRegW<'_>,
+) -> (RetReg, RetReg) {
+    let r1;
+    let r2;
+    unsafe {
+        asm!(
+            ".set push",
+            ".set noat",
+            trap!(),
+            ".set pop",
+            inout("$2") 1_usize => r1,
+            out("$3") r2,
+            inout("$4") arg1.0 => _,
+            inout("$5") arg2.0 => _,
+            in("$6") arg3.0,
+            in("$25") op.0,
+            options(nostack),
+        );
+    }
+    (RetReg(r1), RetReg(r2))
+}
+
 /// Raw semihosting call with 3 parameters that will be read (but not modified) by the host.
 #[inline]
 pub unsafe fn syscall3_readonly(
@@ -15,11 +15,11 @@
 )]
 mod arch;
 
-pub(crate) use self::arch::syscall1_noreturn_readonly;
 pub use self::arch::{
     syscall0, syscall1, syscall1_readonly, syscall2, syscall2_readonly, syscall3,
     syscall3_readonly, syscall4, syscall4_readonly,
 };
+pub(crate) use self::arch::{syscall1_noreturn_readonly, syscall3_readonly_arg1};
 pub use crate::sys::reg::{ParamRegR, ParamRegW, RetReg};
 
 /// Semihosting operation code.
@@ -42,6 +42,9 @@ impl OperationCode {
     pub const UHI_PLOG: Self = Self(13);
     // pub const UHI_ASSERT: Self = Self(14); // TODO(mips)
     // pub const UHI_EXCEPTION: Self = Self(15); // QEMU (as of 10.2) doesn't support this
+    pub const UHI_FINDFIRST: Self = Self(16); // QEMU (as of 10.2) doesn't support this
+    pub const UHI_FINDNEXT: Self = Self(17); // QEMU (as of 10.2) doesn't support this
+    pub const UHI_FINDCLOSE: Self = Self(18); // QEMU (as of 10.2) doesn't support this
     pub const UHI_PREAD: Self = Self(19); // QEMU (as of 10.2) doesn't support this
     pub const UHI_PWRITE: Self = Self(20); // QEMU (as of 10.2) doesn't support this
     pub const UHI_LINK: Self = Self(22); // QEMU (as of 10.2) doesn't support this
@@ -19,6 +19,9 @@
     clippy::upper_case_acronyms
 )]
 
+#[macro_use]
+mod errno;
+
 #[cfg(any(
     target_arch = "aarch64",
     target_arch = "arm",
@@ -79,7 +82,20 @@ pub mod mips;
 
 #[cfg(feature = "args")]
 pub(crate) mod env;
-mod errno;
+#[cfg(any(target_has_atomic = "32", feature = "portable-atomic"))]
+#[cfg(any(
+    feature = "random",
+    all(
+        feature = "stdio",
+        not(any(
+            target_arch = "mips",
+            target_arch = "mips32r6",
+            target_arch = "mips64",
+            target_arch = "mips64r6",
+        )),
+    ),
+))]
+pub(crate) mod once;
 #[cfg(feature = "random")]
 pub(crate) mod random;
 mod reg;
@@ -91,10 +107,11 @@ use core::mem::MaybeUninit;
 #[cfg(feature = "stdio")]
 pub(crate) use self::arch::stdio;
 #[cfg(feature = "fs")]
-pub(crate) use self::arch::{errno::EINVAL, fs};
+pub(crate) use self::arch::fs;
+pub use self::errno::Errno;
 pub(crate) use self::{
-    arch::exit,
-    errno::{decode_error_kind, is_interrupted},
+    arch::{errno::EINVAL, exit},
+    errno::{decode_error_kind, error_string, is_interrupted},
 };
 use crate::{
     fd::{BorrowedFd, RawFd},
@@ -168,6 +185,67 @@ pub fn read_uninit<'a>(
     arch::read_uninit(fd, buf)
 }
 
+/// Reads to the specified buffer starting from a given offset, returning how
+/// many bytes were read.
+///
+/// # Platform-specific behavior
+///
+/// The following semihosting calls are currently being used:
+///
+/// | Platform                                                      | Semihosting call       |
+/// | ------------------------------------------------------------- | ---------------------- |
+/// | AArch64, Arm, RISC-V, LoongArch, Xtensa (openocd-semihosting) | [SYS_SEEK], [SYS_READ] |
+/// | MIPS32, MIPS64                                                | UHI_pread              |
+///
+/// [SYS_SEEK]: https://github.com/ARM-software/abi-aa/blob/2025Q4/semihosting/semihosting.rst#sys-seek-0x0a
+/// [SYS_READ]: https://github.com/ARM-software/abi-aa/blob/2025Q4/semihosting/semihosting.rst#sys-read-0x06
+///
+/// **Disclaimer:** These semihosting calls might change over time.
+///
+/// Arm semihosting has no positional read, and has no way to query the current
+/// file offset, so on AArch64, Arm, RISC-V, LoongArch, and Xtensa, this seeks
+/// to `offset` before reading and the file offset is *not* restored.
+/// [`fs::FileExt::read_at`](crate::fs::FileExt::read_at) restores the file offset.
+#[doc(alias = "UHI_pread")] // mips
+pub fn pread(fd: BorrowedFd<'_>, buf: &mut [u8], offset: u64) -> io::Result<usize> {
+    arch::pread(fd, buf, to_offset(offset)?)
+}
+
+/// Writes from the specified buffer starting from a given offset, returning how
+/// many bytes were written.
+///
+/// # Platform-specific behavior
+///
+/// The following semihosting calls are currently being used:
+///
+/// | Platform                                                      | Semihosting call        |
+/// | ------------------------------------------------------------- | ----------------------- |
+/// | AArch64, Arm, RISC-V, LoongArch, Xtensa (openocd-semihosting) | [SYS_SEEK], [SYS_WRITE] |
+/// | MIPS32, MIPS64                                                | UHI_pwrite              |
+///
+/// [SYS_SEEK]: https://github.com/ARM-software/abi-aa/blob/2025Q4/semihosting/semihosting.rst#sys-seek-0x0a
+/// [SYS_WRITE]: https://github.com/ARM-software/abi-aa/blob/2025Q4/semihosting/semihosting.rst#sys-write-0x05
+///
+/// **Disclaimer:** These semihosting calls might change over time.
+///
+/// Arm semihosting has no positional write, and has no way to query the current
+/// file offset, so on AArch64, Arm, RISC-V, LoongArch, and Xtensa, this seeks
+/// to `offset` before writing and the file offset is *not* restored.
+/// [`fs::FileExt::write_at`](crate::fs::FileExt::write_at) restores the file offset.
+#[doc(alias = "UHI_pwrite")] // mips
+pub fn pwrite(fd: BorrowedFd<'_>, buf: &[u8], offset: u64) -> io::Result<usize> {
+    arch::pwrite(fd, buf, to_offset(offset)?)
+}
+
+#[allow(clippy::cast_sign_loss)]
+fn to_offset(offset: u64) -> io::Result<usize> {
+    // Offsets larger than isize::MAX are rejected by the host (off_t is signed).
+    match isize::try_from(offset) {
+        Ok(offset) => Ok(offset as usize),
+        Err(_) => Err(io::Error::EINVAL),
+    }
+}
+
 /// Writes from the specified buffer, returning how many bytes were written.
 ///
 /// # Platform-specific behavior
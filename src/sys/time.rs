@@ -27,6 +27,21 @@ struct Timespec {
 }
 
 impl SystemTime {
+    #[cfg(feature = "fs")]
+    #[cfg(any(
+        all(doc, docsrs),
+        target_arch = "mips",
+        target_arch = "mips32r6",
+        target_arch = "mips64",
+        target_arch = "mips64r6",
+    ))]
+    pub(crate) fn from_unix_secs(secs: u64) -> io::Result<Self> {
+        match i64::try_from(secs) {
+            Ok(tv_sec) => Ok(Self { t: Timespec::new_unchecked(tv_sec, 0) }),
+            Err(_) => Err(io::Error::INVALID_TIMESTAMP),
+        }
+    }
+
     pub(crate) fn sub_time(&self, other: &Self) -> Result<Duration, Duration> {
         self.t.sub_timespec(&other.t)
     }
@@ -142,6 +157,11 @@ pub(crate) struct Instant {
 }
 
 impl Instant {
+    /// The zero point of the clock, i.e., the start of the execution on
+    /// platforms using SYS_CLOCK.
+    #[cfg(feature = "log")]
+    pub(crate) const START: Self = Self { t: Timespec::zero() };
+
     pub(crate) fn checked_sub_instant(&self, other: &Instant) -> Option<Duration> {
         self.t.sub_timespec(&other.t).ok()
     }
@@ -52,6 +52,44 @@ pub(crate) unsafe fn slice_assume_init_mut<T>(s: &mut [MaybeUninit<T>]) -> &mut
     unsafe { &mut *(s as *mut [MaybeUninit<T>] as *mut [T]) }
 }
 
+/// A fixed-size buffer that accumulates bytes and passes them to an output in
+/// chunks of at most `N` bytes.
+///
+/// This is used by loggers to write each record with as few semihosting calls
+/// as possible (usually one per record).
+#[cfg(any(feature = "defmt", feature = "log"))]
+pub(crate) struct ChunkBuf<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+}
+#[cfg(any(feature = "defmt", feature = "log"))]
+impl<const N: usize> ChunkBuf<N> {
+    pub(crate) const fn new() -> Self {
+        Self { buf: [0; N], len: 0 }
+    }
+    /// Appends `bytes` to the buffer. When the buffer becomes full, its
+    /// contents are passed to `out` before the remaining bytes are appended.
+    pub(crate) fn write(&mut self, mut bytes: &[u8], mut out: impl FnMut(&[u8])) {
+        while !bytes.is_empty() {
+            if self.len == N {
+                self.flush(&mut out);
+            }
+            let n = bytes.len().min(N - self.len);
+            self.buf[self.len..self.len + n].copy_from_slice(&bytes[..n]);
+            self.len += n;
+            bytes = &bytes[n..];
+        }
+    }
+    /// Passes the buffered bytes (if any) to `out` and clears the buffer.
+    pub(crate) fn flush(&mut self, mut out: impl FnMut(&[u8])) {
+        let len = self.len;
+        self.len = 0;
+        if len != 0 {
+            out(&self.buf[..len]);
+        }
+    }
+}
+
 // This module provides core::ptr strict_provenance/exposed_provenance polyfill for pre-1.84 rustc.
 pub(crate) mod ptr {
     cfg_sel!({
@@ -141,3 +179,30 @@ pub(crate) mod ptr {
         }
     });
 }
+
+#[cfg(test)]
+#[allow(clippy::std_instead_of_alloc, clippy::std_instead_of_core)]
+mod tests {
+    #[cfg(any(feature = "defmt", feature = "log"))]
+    #[test]
+    fn chunk_buf() {
+        use std::{vec, vec::Vec};
+
+        let mut buf = super::ChunkBuf::<4>::new();
+        let mut chunks: Vec<Vec<u8>> = vec![];
+        buf.flush(|b| chunks.push(b.to_vec()));
+        assert!(chunks.is_empty());
+        buf.write(b"ab", |b| chunks.push(b.to_vec()));
+        buf.write(b"", |b| chunks.push(b.to_vec()));
+        assert!(chunks.is_empty());
+        buf.write(b"cd", |b| chunks.push(b.to_vec()));
+        // A full buffer is kept until more bytes are written or it is flushed.
+        assert!(chunks.is_empty());
+        buf.write(b"efghijk", |b| chunks.push(b.to_vec()));
+        assert_eq!(chunks, [&b"abcd"[..], b"efgh"]);
+        buf.flush(|b| chunks.push(b.to_vec()));
+        assert_eq!(chunks, [&b"abcd"[..], b"efgh", b"ijk"]);
+        buf.flush(|b| chunks.push(b.to_vec()));
+        assert_eq!(chunks.len(), 3);
+    }
+}
@@ -6,21 +6,21 @@
 
 #[cfg(arm_compat)]
 use core::ptr;
-use core::str;
+use core::{ffi::CStr, str};
 
 #[cfg(not(mips))]
-use semihosting::experimental::time::{Duration, Instant, SystemTime};
+use semihosting::experimental::time::{Duration, Instant};
 #[cfg(arm_compat)]
 use semihosting::sys::arm_compat::*;
 #[cfg(mips)]
 use semihosting::sys::mips::*;
 use semihosting::{
     c, dbg,
-    experimental::{env, random},
+    experimental::{env, random, time::SystemTime},
     fd::AsFd as _,
-    fs,
+    fs::{self, FileExt as _},
     io::{self, IsTerminal as _, Read as _, Seek as _, Write as _},
-    print, println,
+    print, println, udbg, uprintln,
 };
 #[cfg(feature = "panic-unwind")]
 use semihosting::{experimental::panic, process};
@@ -99,8 +99,14 @@ fn run() {
                 println!("stdout2: {}", stdout2.as_fd().as_raw_fd());
                 println!("stderr1: {}", stderr1.as_fd().as_raw_fd());
                 println!("stderr2: {}", stderr2.as_fd().as_raw_fd());
-                assert_ne!(stdout1.as_fd().as_raw_fd(), stdout2.as_fd().as_raw_fd());
-                assert_ne!(stderr1.as_fd().as_raw_fd(), stderr2.as_fd().as_raw_fd());
+                if cfg!(any(target_has_atomic = "32", feature = "portable-atomic")) {
+                    // stdio streams are opened once and reused.
+                    assert_eq!(stdout1.as_fd().as_raw_fd(), stdout2.as_fd().as_raw_fd());
+                    assert_eq!(stderr1.as_fd().as_raw_fd(), stderr2.as_fd().as_raw_fd());
+                } else {
+                    assert_ne!(stdout1.as_fd().as_raw_fd(), stdout2.as_fd().as_raw_fd());
+                    assert_ne!(stderr1.as_fd().as_raw_fd(), stderr2.as_fd().as_raw_fd());
+                }
             }
             #[cfg(mips)]
             {
@@ -134,6 +140,18 @@ fn run() {
             let f1 = io::stdout().unwrap().as_fd().as_raw_fd();
             assert_eq!(io::stdout().unwrap().as_fd().as_raw_fd(), f1);
 
+            let stdout = io::stdout().unwrap();
+            let mut lock = stdout.lock();
+            write!(lock, "hello").unwrap();
+            lock.write_all(b" ").unwrap();
+            writeln!(lock, "world").unwrap();
+            lock.write_all(b"buffered until drop\n").unwrap();
+            lock.write_all(&[b'.'; 1024]).unwrap();
+            lock.write_all(b"\n").unwrap();
+            lock.flush().unwrap();
+            drop(lock);
+            (&stdout).write_all(b"hello\n").unwrap();
+
             let mut stdin = io::stdin().unwrap();
             if cfg!(mips) {
                 assert_eq!(stdin.as_fd().as_raw_fd(), 0);
@@ -157,6 +175,8 @@ fn run() {
             }
         }
         dbg!(());
+        udbg!(());
+        uprintln!("uprintln: {} {:?}", 1, (2u8, -3i32));
         println!("ok");
     }
     {
@@ -168,6 +188,23 @@ fn run() {
             let mut file = fs::File::create(path_a).unwrap();
             assert_eq!(file.is_terminal(), false);
             assert_eq!(file.metadata().unwrap().len(), 0);
+            let metadata = file.metadata().unwrap();
+            // Arm semihosting has no way to query the file type.
+            assert_eq!(metadata.is_file(), cfg!(mips));
+            assert!(!metadata.is_dir());
+            assert!(!metadata.is_symlink());
+            assert!(!metadata.file_type().is_char_device());
+            assert!(!metadata.permissions().readonly());
+            // The timestamp methods of fs::Metadata require the `time` feature of
+            // semihosting, which is always enabled in Cargo.toml of this crate.
+            if cfg!(mips) {
+                assert!(metadata.modified().unwrap() > SystemTime::UNIX_EPOCH);
+                assert!(metadata.accessed().unwrap() > SystemTime::UNIX_EPOCH);
+            } else {
+                assert_eq!(metadata.modified().unwrap_err().kind(), io::ErrorKind::Unsupported);
+                assert_eq!(metadata.accessed().unwrap_err().kind(), io::ErrorKind::Unsupported);
+            }
+            assert_eq!(metadata.created().unwrap_err().kind(), io::ErrorKind::Unsupported);
             #[cfg(mips)]
             println!("mips_fstat: {:?}", mips_fstat(file.as_fd()).unwrap());
             file.write_all(b"abb").unwrap();
@@ -246,15 +283,134 @@ fn run() {
             file.read_exact(&mut buf[..]).unwrap();
             let s = str::from_utf8(&buf).unwrap();
             assert_eq!(s, "abcd");
+            assert_eq!(file.stream_position().unwrap(), 4);
+            assert_eq!(file.seek(io::SeekFrom::Current(-3)).unwrap(), 1);
+            let n = file.read(&mut buf[..2]).unwrap();
+            assert_eq!(str::from_utf8(&buf[..n]).unwrap(), "bc");
+            file.seek_relative(-1).unwrap();
+            assert_eq!(file.stream_position().unwrap(), 2);
+            assert_eq!(
+                file.seek(io::SeekFrom::Current(-3)).unwrap_err().kind(),
+                io::ErrorKind::InvalidInput
+            );
+            assert_eq!(file.seek(io::SeekFrom::End(-1)).unwrap(), 4);
+            assert_eq!(file.stream_position().unwrap(), 4);
             drop(file);
 
-            // rename
-            if cfg!(mips) {
+            // read_into
+            let mut buf = [0; 8];
+            assert_eq!(fs::read_into(path_a, &mut buf).unwrap(), b"abcde");
+            let mut buf = [0; 5];
+            assert_eq!(fs::read_into(path_a, &mut buf).unwrap(), b"abcde");
+            let mut buf = [0; 4];
+            assert_eq!(
+                fs::read_into(path_a, &mut buf).unwrap_err().kind(),
+                io::ErrorKind::FileTooLarge
+            );
+
+            // metadata/exists
+            assert_eq!(fs::metadata(path_a).unwrap().len(), 5);
+            assert_eq!(fs::symlink_metadata(path_a).unwrap().len(), 5);
+            assert_eq!(fs::exists(path_a).unwrap(), true);
+            assert_eq!(fs::metadata(path_b).unwrap_err().kind(), io::ErrorKind::NotFound);
+            assert_eq!(fs::exists(path_b).unwrap(), false);
+
+            // FileExt
+            // QEMU (as of 10.2) doesn't support UHI_pread/UHI_pwrite and aborts on
+            // unsupported UHI operations, so there is no error to check on MIPS.
+            if !cfg!(mips) {
+                let file = fs::OpenOptions::new().read(true).write(true).open(path_a).unwrap();
+                let mut buf = [0; 3];
+                file.read_exact_at(&mut buf, 1).unwrap();
+                assert_eq!(&buf, b"bcd");
+                assert_eq!(file.read_at(&mut buf, 4).unwrap(), 1);
+                assert_eq!(buf[0], b'e');
                 assert_eq!(
-                    fs::rename(path_a, path_b).unwrap_err().kind(),
-                    io::ErrorKind::Unsupported
+                    file.read_exact_at(&mut buf, 4).unwrap_err().kind(),
+                    io::ErrorKind::UnexpectedEof
                 );
-            } else {
+                file.write_all_at(b"C", 2).unwrap();
+                assert_eq!((&file).stream_position().unwrap(), 0);
+                let mut buf = [0; 8];
+                assert_eq!(fs::read_into(path_a, &mut buf).unwrap(), b"abCde");
+                file.write_all_at(b"c", 2).unwrap();
+                drop(file);
+            }
+
+            // io::copy
+            let path_c = c!("c.txt");
+            let mut file = fs::File::open(path_a).unwrap();
+            let mut file_c = fs::File::create(path_c).unwrap();
+            assert_eq!(io::copy(&mut file, &mut file_c).unwrap(), 5);
+            drop(file_c);
+            file.rewind().unwrap();
+            assert_eq!(io::copy(&mut (&mut file).take(2), &mut io::sink()).unwrap(), 2);
+            assert_eq!(file.stream_position().unwrap(), 2);
+            drop(file);
+            let mut buf = [0; 8];
+            assert_eq!(fs::read_into(path_c, &mut buf).unwrap(), b"abcde");
+            fs::remove_file(path_c).unwrap();
+
+            // fs::copy
+            assert_eq!(fs::copy(path_a, path_c).unwrap(), 5);
+            let mut buf = [0; 8];
+            assert_eq!(fs::read_into(path_c, &mut buf).unwrap(), b"abcde");
+            fs::write(path_c, "too long content").unwrap();
+            assert_eq!(fs::copy(path_a, path_c).unwrap(), 5);
+            assert_eq!(fs::read_into(path_c, &mut buf).unwrap(), b"abcde");
+            #[cfg(mips)]
+            assert_eq!(
+                mips_fstat(fs::File::open(path_c).unwrap().as_fd()).unwrap().st_mode & 0o777,
+                mips_fstat(fs::File::open(path_a).unwrap().as_fd()).unwrap().st_mode & 0o777
+            );
+            // Copying from a directory fails without truncating the destination.
+            fs::copy(c!("."), path_c).unwrap_err();
+            assert_eq!(fs::read_into(path_c, &mut buf).unwrap(), b"abcde");
+            fs::remove_file(path_c).unwrap();
+            assert_eq!(fs::copy(path_c, path_a).unwrap_err().kind(), io::ErrorKind::NotFound);
+
+            // create_new
+            assert_eq!(
+                fs::OpenOptions::new()
+                    .write(true)
+                    .create_new(true)
+                    .open(path_a)
+                    .unwrap_err()
+                    .kind(),
+                io::ErrorKind::AlreadyExists
+            );
+            let path_d = c!("d.txt");
+            let mut file =
+                fs::OpenOptions::new().write(true).create_new(true).open(path_d).unwrap();
+            file.write_all(b"d").unwrap();
+            drop(file);
+            let mut buf = [0; 8];
+            assert_eq!(fs::read_into(path_d, &mut buf).unwrap(), b"d");
+            fs::remove_file(path_d).unwrap();
+
+            // tempfile
+            let mut tmp = fs::tempfile().unwrap();
+            let tmp2 = fs::tempfile().unwrap();
+            assert_ne!(tmp.path(), tmp2.path());
+            drop(tmp2);
+            tmp.write_all(b"tmp").unwrap();
+            tmp.rewind().unwrap();
+            let mut buf = [0; 8];
+            let n = tmp.read(&mut buf[..]).unwrap();
+            assert_eq!(&buf[..n], b"tmp");
+            let mut path_buf = [0; 256];
+            let path = tmp.path().to_bytes_with_nul();
+            path_buf[..path.len()].copy_from_slice(path);
+            let path = CStr::from_bytes_with_nul(&path_buf[..path.len()]).unwrap();
+            assert_eq!(fs::exists(path).unwrap(), true);
+            tmp.close().unwrap();
+            assert_eq!(fs::exists(path).unwrap(), false);
+
+            // rename
+            // On MIPS, rename is emulated with UHI_link, but QEMU (as of 10.2) doesn't
+            // support UHI_link and aborts on unsupported UHI operations, so there is
+            // no error to check on MIPS.
+            if !cfg!(mips) {
                 fs::rename(path_a, path_b).unwrap();
                 assert_eq!(fs::File::open(path_a).unwrap_err().kind(), io::ErrorKind::NotFound);
                 let mut file = fs::File::open(path_b).unwrap();
@@ -266,10 +422,35 @@ fn run() {
                 fs::rename(path_b, path_a).unwrap();
                 fs::File::open(path_a).unwrap();
                 assert_eq!(fs::File::open(path_b).unwrap_err().kind(), io::ErrorKind::NotFound);
+                // Windows host's rename doesn't replace the existing file.
+                if !cfg!(host_os = "windows") {
+                    fs::write(path_b, "b").unwrap();
+                    fs::rename(path_b, path_a).unwrap();
+                    let mut buf = [0; 8];
+                    assert_eq!(fs::read_into(path_a, &mut buf).unwrap(), b"b");
+                    assert_eq!(fs::File::open(path_b).unwrap_err().kind(), io::ErrorKind::NotFound);
+                    fs::write(path_a, "abcde").unwrap();
+                }
+            }
+
+            // read_dir
+            // QEMU (as of 10.2) doesn't support UHI_findfirst.
+            if !cfg!(mips) {
+                assert_eq!(fs::read_dir(c!(".")).unwrap_err().kind(), io::ErrorKind::Unsupported);
+            }
+
+            // hard_link
+            // QEMU (as of 10.2) doesn't support UHI_link (see rename above).
+            if !cfg!(mips) {
+                assert_eq!(
+                    fs::hard_link(path_a, path_b).unwrap_err().kind(),
+                    io::ErrorKind::Unsupported
+                );
             }
 
             fs::remove_file(path_a).unwrap();
             assert_eq!(fs::File::open(path_a).unwrap_err().kind(), io::ErrorKind::NotFound);
+            assert_eq!(fs::exists(path_a).unwrap(), false);
         }
         println!("ok");
     }
@@ -386,6 +567,25 @@ fn run() {
         sys_writec(b'\n');
         print!("sys_write0: ");
         sys_write0(c!("bc\n"));
+        // special file names
+        {
+            use semihosting::sys::arm_compat::fs::OpenOptionsExt as _;
+
+            let mut tt = fs::OpenOptions::new()
+                .write(true)
+                .create(true)
+                .truncate(true)
+                .text(true)
+                .open(c!(":tt"))
+                .unwrap();
+            tt.write_all(b"fs::File(:tt): ok\n").unwrap();
+            drop(tt);
+            if !qemu_has_read_order_bug {
+                let mut buf = [0; 8];
+                let features = fs::read_into(c!(":semihosting-features"), &mut buf).unwrap();
+                assert_eq!(&features[..4], b"SHFB");
+            }
+        }
         println!("ok");
     }
     #[cfg(mips)]